@@ -1,33 +1,70 @@
+pub mod marble;
+pub mod scheduler;
 pub mod stream;
 pub mod stream_ext;
 pub mod subject;
 
 pub use crate::{
+    scheduler::{test_scheduler::*, Clock, Scheduler, SystemClock},
     stream::controller::*,
     stream::event::*,
     stream::event_lite::*,
     stream::notification::*,
     stream::observable::*,
     stream::rx::combine_latest::*,
+    stream::rx::combine_latest_all::*,
+    stream::rx::concat::*,
+    stream::rx::concat_all::*,
+    stream::rx::fork_join::*,
+    stream::rx::fork_join_all::*,
+    stream::rx::merge::*,
+    stream::rx::merge_all::*,
+    stream::rx::race_all::*,
+    stream::rx::retry::*,
+    stream::rx::retry_when::*,
+    stream::rx::with_latest_from::*,
     stream::rx::zip::*,
+    stream::rx::zip_all::*,
+    stream::timer::*,
     stream_ext::RxExt,
     subject::{
         Subject,
-        {behavior_subject::*, publish_subject::*, replay_subject::*},
+        {async_subject::*, behavior_subject::*, publish_subject::*, replay_subject::*},
     },
 };
 
+#[cfg(feature = "async-io")]
+pub use crate::scheduler::async_io_scheduler::*;
+
 pub mod prelude {
     pub use crate::{
+        scheduler::{test_scheduler::TestScheduler, Clock, Scheduler},
+        stream::controller::OverflowStrategy,
         stream::event::*,
         stream::event_lite::*,
         stream::notification::*,
         stream::rx::combine_latest::*,
+        stream::rx::combine_latest_all::*,
+        stream::rx::concat::*,
+        stream::rx::concat_all::*,
+        stream::rx::fork_join::*,
+        stream::rx::fork_join_all::*,
+        stream::rx::merge::*,
+        stream::rx::merge_all::*,
+        stream::rx::race_all::*,
+        stream::rx::retry::*,
+        stream::rx::retry_when::*,
+        stream::rx::with_latest_from::*,
         stream::rx::zip::*,
+        stream::rx::zip_all::*,
+        stream::timer::*,
         stream_ext::RxExt,
         subject::{
             Subject,
-            {behavior_subject::*, publish_subject::*, replay_subject::*},
+            {async_subject::*, behavior_subject::*, publish_subject::*, replay_subject::*},
         },
     };
+
+    #[cfg(feature = "async-io")]
+    pub use crate::scheduler::async_io_scheduler::*;
 }
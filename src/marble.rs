@@ -0,0 +1,468 @@
+//! Marble diagram testing, driven by a [`TestScheduler`].
+//!
+//! Marble strings describe events over virtual time, where every character takes one [`FRAME`]:
+//! - `-` is an empty frame
+//! - any other character, except for the ones below, is an event carrying that character
+//! - `|` completes the `Stream`
+//! - `#` errors the `Stream` with a [`MarbleError`], see [`cold_try`] and [`assert_try_marbles`]
+//! - `(` and `)` group events, which then all occur at the frame of the `(`
+//! - spaces are ignored and can be used for alignment
+//!
+//! `^` and `!` are reserved, and cause a panic.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use futures_rx::{marble::{assert_marbles, cold}, RxExt, TestScheduler};
+//!
+//! let scheduler = TestScheduler::new();
+//! let stream = cold("-a-b------c|", &scheduler).debounce_time(Duration::from_millis(3), &scheduler);
+//!
+//! assert_marbles(&scheduler, stream, "------b----(c|)");
+//! ```
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    convert::Infallible,
+    error::Error,
+    fmt::{self, Write},
+    pin::Pin,
+    rc::Rc,
+    task::{ready, Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::{stream::FusedStream, Future, Stream, StreamExt};
+use pin_project_lite::pin_project;
+
+use crate::{Clock, Notification, Scheduler, TestScheduler};
+
+/// The virtual time which every character in a marble string takes.
+pub const FRAME: Duration = Duration::from_millis(1);
+
+/// The error which `#` stands for in a marble string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarbleError;
+
+impl fmt::Display for MarbleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "marble error")
+    }
+}
+
+impl Error for MarbleError {}
+
+/// Parses a marble string into its events, each paired with the frame at which it occurs.
+///
+/// # Panics
+///
+/// Panics on unbalanced groups and on reserved characters.
+pub fn parse(marbles: &str) -> Vec<(usize, Notification<char, MarbleError>)> {
+    let mut events = Vec::new();
+    let mut group_frame = None;
+
+    for (frame, char) in marbles.chars().filter(|it| !it.is_whitespace()).enumerate() {
+        let event_frame = group_frame.unwrap_or(frame);
+
+        match char {
+            '-' => {}
+            '(' => {
+                assert!(group_frame.is_none(), "nested groups in {marbles:?}");
+
+                group_frame = Some(frame);
+            }
+            ')' => {
+                assert!(group_frame.is_some(), "unmatched `)` in {marbles:?}");
+
+                group_frame = None;
+            }
+            '|' => events.push((event_frame, Notification::Complete)),
+            '#' => events.push((event_frame, Notification::Error(MarbleError))),
+            '^' | '!' => panic!("unsupported marble character `{char}` in {marbles:?}"),
+            it => events.push((event_frame, Notification::Next(it))),
+        }
+    }
+
+    assert!(group_frame.is_none(), "unmatched `(` in {marbles:?}");
+
+    events
+}
+
+/// Formats events back into a marble string, the inverse of [`parse`].
+pub fn format(events: &[(usize, Notification<char, MarbleError>)]) -> String {
+    let mut marbles = String::new();
+    let mut frame = 0;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event_frame = events[index].0;
+        let count = events[index..]
+            .iter()
+            .take_while(|(it, _)| *it == event_frame)
+            .count();
+
+        while frame < event_frame {
+            marbles.push('-');
+            frame += 1;
+        }
+
+        let group = &events[index..index + count];
+
+        if count > 1 {
+            marbles.push('(');
+        }
+
+        for (_, event) in group {
+            match event {
+                Notification::Next(it) => marbles.push(*it),
+                Notification::Complete => marbles.push('|'),
+                Notification::Error(_) => marbles.push('#'),
+            }
+        }
+
+        if count > 1 {
+            marbles.push(')');
+            frame += 2;
+        }
+
+        frame += count;
+        index += count;
+    }
+
+    marbles
+}
+
+pin_project! {
+    /// Stream for the [`cold`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Cold<Sch: Scheduler> {
+        scheduler: Sch,
+        events: VecDeque<(usize, Notification<char, MarbleError>)>,
+        start: Option<Instant>,
+        #[pin]
+        sleep: Option<Sch::Sleep>,
+        is_done: bool,
+    }
+}
+
+pin_project! {
+    /// Stream for the [`cold_try`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryCold<Sch: Scheduler> {
+        #[pin]
+        cold: Cold<Sch>,
+    }
+}
+
+/// Creates a `Stream` which emits the events of `marbles` on `scheduler`,
+/// with time starting when the `Stream` is first polled.
+///
+/// Without `|`, the `Stream` never completes.
+///
+/// # Panics
+///
+/// Panics if `marbles` contains `#`, use [`cold_try`] instead.
+pub fn cold<Sch: Scheduler>(marbles: &str, scheduler: &Sch) -> Cold<Sch> {
+    let events = parse(marbles);
+
+    assert!(
+        !events
+            .iter()
+            .any(|(_, it)| matches!(it, Notification::Error(_))),
+        "`#` in {marbles:?} requires `cold_try`"
+    );
+
+    Cold::new(events, scheduler)
+}
+
+/// Like [`cold`], but emits every event as `Ok`, and `#` as a final `Err(MarbleError)`.
+pub fn cold_try<Sch: Scheduler>(marbles: &str, scheduler: &Sch) -> TryCold<Sch> {
+    TryCold {
+        cold: Cold::new(parse(marbles), scheduler),
+    }
+}
+
+impl<Sch: Scheduler> Cold<Sch> {
+    fn new(events: Vec<(usize, Notification<char, MarbleError>)>, scheduler: &Sch) -> Self {
+        Self {
+            scheduler: scheduler.clone(),
+            events: events.into(),
+            start: None,
+            sleep: None,
+            is_done: false,
+        }
+    }
+
+    fn poll_notification(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Notification<char, MarbleError>> {
+        let mut this = self.project();
+
+        if *this.is_done {
+            return Poll::Ready(Notification::Complete);
+        }
+
+        let start = *this.start.get_or_insert_with(|| this.scheduler.now());
+        let Some((frame, _)) = this.events.front() else {
+            return Poll::Pending;
+        };
+
+        if this.sleep.is_none() {
+            let deadline = start + FRAME * *frame as u32;
+
+            this.sleep.set(Some(this.scheduler.sleep_until(deadline)));
+        }
+
+        if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+            ready!(sleep.poll(cx));
+        }
+
+        this.sleep.set(None);
+
+        match this.events.pop_front() {
+            Some((_, Notification::Next(it))) => Poll::Ready(Notification::Next(it)),
+            Some((_, Notification::Error(error))) => {
+                *this.is_done = true;
+
+                Poll::Ready(Notification::Error(error))
+            }
+            _ => {
+                *this.is_done = true;
+
+                Poll::Ready(Notification::Complete)
+            }
+        }
+    }
+
+    fn next_count(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|(_, it)| matches!(it, Notification::Next(_)))
+            .count()
+    }
+}
+
+impl<Sch: Scheduler> FusedStream for Cold<Sch> {
+    fn is_terminated(&self) -> bool {
+        self.is_done
+    }
+}
+
+impl<Sch: Scheduler> Stream for Cold<Sch> {
+    type Item = char;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match ready!(self.poll_notification(cx)) {
+            Notification::Next(it) => Poll::Ready(Some(it)),
+            Notification::Error(_) | Notification::Complete => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.next_count(), None)
+    }
+}
+
+impl<Sch: Scheduler> FusedStream for TryCold<Sch> {
+    fn is_terminated(&self) -> bool {
+        self.cold.is_terminated()
+    }
+}
+
+impl<Sch: Scheduler> Stream for TryCold<Sch> {
+    type Item = Result<char, MarbleError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match ready!(self.project().cold.poll_notification(cx)) {
+            Notification::Next(it) => Poll::Ready(Some(Ok(it))),
+            Notification::Error(error) => Poll::Ready(Some(Err(error))),
+            Notification::Complete => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cold.next_count(), None)
+    }
+}
+
+/// Runs `stream` on `scheduler` until idle, and asserts that it emitted the events of `expected`,
+/// with time starting now.
+#[track_caller]
+pub fn assert_marbles<S: Stream<Item = char> + 'static>(
+    scheduler: &TestScheduler,
+    stream: S,
+    expected: &str,
+) {
+    assert_try_marbles(scheduler, stream.map(Ok::<_, Infallible>), expected);
+}
+
+/// Like [`assert_marbles`], but for a `Stream` of `Result`s, where any `Err` is `#`.
+///
+/// The `Stream` is no longer polled after it emitted an `Err`.
+#[track_caller]
+pub fn assert_try_marbles<S: Stream<Item = Result<char, E>> + 'static, E: 'static>(
+    scheduler: &TestScheduler,
+    stream: S,
+    expected: &str,
+) {
+    let start = scheduler.now();
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let frame_of = {
+        let scheduler = scheduler.clone();
+
+        move || (scheduler.now().duration_since(start).as_nanos() / FRAME.as_nanos()) as usize
+    };
+
+    scheduler.spawn({
+        let events = Rc::clone(&events);
+        let frame_of = frame_of.clone();
+
+        async move {
+            let mut stream = Box::pin(stream);
+
+            while let Some(it) = stream.next().await {
+                let Ok(it) = it else {
+                    events
+                        .borrow_mut()
+                        .push((frame_of(), Notification::Error(MarbleError)));
+
+                    return;
+                };
+
+                events
+                    .borrow_mut()
+                    .push((frame_of(), Notification::Next(it)));
+            }
+
+            events
+                .borrow_mut()
+                .push((frame_of(), Notification::Complete));
+        }
+    });
+
+    scheduler.run_until_idle();
+
+    let actual = events.take();
+    let expected = parse(expected);
+
+    if actual != expected {
+        let mut message = String::new();
+
+        writeln!(message, "marbles differ").unwrap();
+        writeln!(message, "expected: {}", format(&expected)).unwrap();
+        write!(message, "  actual: {}", format(&actual)).unwrap();
+
+        panic!("{message}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+
+    use crate::{
+        marble::{assert_marbles, assert_try_marbles, cold, cold_try, format, parse, MarbleError},
+        Notification, RxExt, TestScheduler,
+    };
+
+    #[test]
+    fn smoke() {
+        let events = parse("-a-(bc)-d|");
+
+        assert_eq!(
+            events,
+            [
+                (1, Notification::Next('a')),
+                (3, Notification::Next('b')),
+                (3, Notification::Next('c')),
+                (8, Notification::Next('d')),
+                (9, Notification::Complete),
+            ]
+        );
+        assert_eq!(format(&events), "-a-(bc)-d|");
+    }
+
+    #[test]
+    fn parses_errors() {
+        let events = parse("-a-#");
+
+        assert_eq!(
+            events,
+            [
+                (1, Notification::Next('a')),
+                (3, Notification::Error(MarbleError)),
+            ]
+        );
+        assert_eq!(format(&events), "-a-#");
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported marble character `^`")]
+    fn rejects_reserved_characters() {
+        parse("-^-a|");
+    }
+
+    #[test]
+    #[should_panic(expected = "requires `cold_try`")]
+    fn cold_rejects_errors() {
+        let _ = cold("-a#", &TestScheduler::new());
+    }
+
+    #[test]
+    fn cold_try_stream() {
+        let scheduler = TestScheduler::new();
+
+        assert_try_marbles(&scheduler, cold_try("-a-(b#)", &scheduler), "-a-(b#)");
+    }
+
+    #[test]
+    fn catch_error() {
+        let scheduler = TestScheduler::new();
+        let stream = cold_try("-a-#", &scheduler).catch_error({
+            let scheduler = scheduler.clone();
+
+            move |_| cold_try("xy|", &scheduler)
+        });
+
+        assert_try_marbles(&scheduler, stream, "-a-xy|");
+    }
+
+    #[test]
+    fn cold_stream() {
+        let scheduler = TestScheduler::new();
+
+        assert_marbles(&scheduler, cold("-a-(bc)-d|", &scheduler), "-a-(bc)-d|");
+    }
+
+    #[test]
+    fn operators() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("ab-c---d-|", &scheduler)
+            .throttle_time(Duration::from_millis(3), &scheduler)
+            .map(|it| it.to_ascii_uppercase());
+
+        assert_marbles(&scheduler, stream, "A--C---D-|");
+
+        let stream = cold("a-b-c|", &scheduler).switch_map({
+            let scheduler = scheduler.clone();
+
+            move |it| {
+                cold("-x-y|", &scheduler).map(move |suffix| if suffix == 'x' { it } else { suffix })
+            }
+        });
+
+        assert_marbles(&scheduler, stream, "-a-b-c-y|");
+    }
+
+    #[test]
+    #[should_panic(expected = "marbles differ")]
+    fn reports_difference() {
+        let scheduler = TestScheduler::new();
+
+        assert_marbles(&scheduler, cold("-a|", &scheduler), "a-|");
+    }
+}
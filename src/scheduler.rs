@@ -0,0 +1,78 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use futures::{future::Map, FutureExt};
+
+use crate::{stream::timer, Timer};
+
+#[cfg(feature = "async-io")]
+pub mod async_io_scheduler;
+pub mod test_scheduler;
+
+/// A source of time for time-based operators.
+///
+/// Implement this trait to run time-based pipelines on a specific runtime,
+/// or against a virtual clock in tests.
+pub trait Scheduler: Clock + Clone {
+    type Sleep: Future<Output = ()>;
+
+    /// Returns a `Future` which resolves once `deadline` is reached.
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep;
+
+    /// Returns a `Future` which resolves once `duration` has passed.
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        self.sleep_until(self.now() + duration)
+    }
+
+    /// Returns a `Stream` which emits an incrementing count, starting at `0`, for every `period` which passes,
+    /// see the [`interval`](crate::interval) function.
+    fn interval(&self, period: Duration) -> Timer<Self> {
+        timer::interval(period, self)
+    }
+}
+
+/// A source of the current time, and the supertrait of every `Scheduler`.
+///
+/// Used where only timestamps are needed, e.g. by `ReplaySubject::window`.
+pub trait Clock {
+    /// Returns the current time, as seen by this clock.
+    fn now(&self) -> Instant;
+}
+
+/// The wall clock, which is the default `Clock` of a `ReplaySubject`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Tracks a fixed time window which opens on the first event of a chunk,
+/// used by the `buffer_time` and `window_time` methods.
+pub(crate) struct Deadline<Sch: Scheduler> {
+    scheduler: Sch,
+    duration: Duration,
+    deadline: Instant,
+}
+
+impl<Sch: Scheduler> Deadline<Sch> {
+    pub(crate) fn new(duration: Duration, scheduler: &Sch) -> Self {
+        Self {
+            scheduler: scheduler.clone(),
+            duration,
+            deadline: scheduler.now() + duration,
+        }
+    }
+
+    pub(crate) fn sleep(&mut self, count: usize) -> Map<Sch::Sleep, fn(()) -> bool> {
+        if count == 1 {
+            self.deadline = self.scheduler.now() + self.duration;
+        }
+
+        self.scheduler.sleep_until(self.deadline).map(|_| true)
+    }
+}
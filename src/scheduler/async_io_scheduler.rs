@@ -0,0 +1,58 @@
+use std::time::Instant;
+
+use async_io::Timer;
+use futures::{future::Map, FutureExt};
+
+use super::{Clock, Scheduler};
+
+/// A `Scheduler` backed by the timers of the `async-io` crate,
+/// which work with any executor.
+///
+/// Requires the `async-io` feature.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsyncIoScheduler;
+
+impl Clock for AsyncIoScheduler {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+impl Scheduler for AsyncIoScheduler {
+    type Sleep = Map<Timer, fn(Instant)>;
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        Timer::at(deadline).map(drop as fn(Instant))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use futures::{executor::block_on, StreamExt};
+
+    use crate::{AsyncIoScheduler, Clock, Scheduler};
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let scheduler = AsyncIoScheduler;
+            let now = scheduler.now();
+
+            scheduler.sleep(Duration::from_millis(50)).await;
+
+            assert!(now.elapsed() >= Duration::from_millis(50));
+
+            let now = Instant::now();
+            let ticks = scheduler
+                .interval(Duration::from_millis(20))
+                .take(3)
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(ticks, [0, 1, 2]);
+            assert!(now.elapsed() >= Duration::from_millis(60));
+        });
+    }
+}
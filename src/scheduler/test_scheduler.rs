@@ -0,0 +1,223 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use futures::{
+    executor::{LocalPool, LocalSpawner},
+    task::LocalSpawnExt,
+};
+
+use super::{Clock, Scheduler};
+
+/// A `Scheduler` running on a virtual clock, for deterministic tests of time-based operators.
+///
+/// Time only moves forward when calling `advance_by` or `run_until_idle`,
+/// which then also run all futures which were spawned using `spawn`.
+///
+/// Do not call `advance_by` or `run_until_idle` from within a spawned future.
+#[derive(Clone)]
+pub struct TestScheduler {
+    clock: Arc<Mutex<VirtualClock>>,
+    pool: Rc<RefCell<LocalPool>>,
+    spawner: LocalSpawner,
+}
+
+struct VirtualClock {
+    now: Instant,
+    next_id: usize,
+    timers: BTreeMap<(Instant, usize), Waker>,
+}
+
+/// Future for the [`sleep_until`](Scheduler::sleep_until) method of `TestScheduler`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TestSleep {
+    clock: Arc<Mutex<VirtualClock>>,
+    deadline: Instant,
+    id: usize,
+}
+
+impl Clock for TestScheduler {
+    fn now(&self) -> Instant {
+        self.clock.lock().unwrap().now
+    }
+}
+
+impl Scheduler for TestScheduler {
+    type Sleep = TestSleep;
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        let mut clock = self.clock.lock().unwrap();
+        let id = clock.next_id;
+
+        clock.next_id += 1;
+
+        TestSleep {
+            clock: Arc::clone(&self.clock),
+            deadline,
+            id,
+        }
+    }
+}
+
+#[allow(clippy::new_without_default)]
+impl TestScheduler {
+    pub fn new() -> Self {
+        let pool = LocalPool::new();
+        let spawner = pool.spawner();
+
+        Self {
+            clock: Arc::new(Mutex::new(VirtualClock {
+                now: Instant::now(),
+                next_id: 0,
+                timers: BTreeMap::new(),
+            })),
+            pool: Rc::new(RefCell::new(pool)),
+            spawner,
+        }
+    }
+
+    /// Spawns a future, which is run whenever time advances.
+    pub fn spawn<Fut: Future<Output = ()> + 'static>(&self, future: Fut) {
+        self.spawner
+            .spawn_local(future)
+            .expect("the pool is owned by the scheduler");
+    }
+
+    /// Moves the virtual clock forward by `duration`, firing all timers which are due in order,
+    /// and running all spawned futures in between.
+    pub fn advance_by(&self, duration: Duration) {
+        let target = self.now() + duration;
+
+        self.run_until_stalled();
+
+        while let Some(deadline) = self.next_deadline().filter(|it| *it <= target) {
+            self.advance_to(deadline);
+        }
+
+        self.advance_to(target);
+    }
+
+    /// Keeps moving the virtual clock forward to the next timer, until there are no more timers left.
+    ///
+    /// Note that this never returns while a spawned future keeps scheduling timers, like an `interval`.
+    pub fn run_until_idle(&self) {
+        self.run_until_stalled();
+
+        while let Some(deadline) = self.next_deadline() {
+            self.advance_to(deadline);
+        }
+    }
+
+    fn advance_to(&self, deadline: Instant) {
+        let wakers = {
+            let mut clock = self.clock.lock().unwrap();
+
+            let now = clock.now.max(deadline);
+            let pending = clock.timers.split_off(&(now, usize::MAX));
+
+            clock.now = now;
+
+            std::mem::replace(&mut clock.timers, pending)
+        };
+
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+
+        self.run_until_stalled();
+    }
+
+    fn next_deadline(&self) -> Option<Instant> {
+        self.clock
+            .lock()
+            .unwrap()
+            .timers
+            .keys()
+            .next()
+            .map(|(deadline, _)| *deadline)
+    }
+
+    fn run_until_stalled(&self) {
+        self.pool.borrow_mut().run_until_stalled();
+    }
+}
+
+impl Future for TestSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut clock = self.clock.lock().unwrap();
+
+        if clock.now >= self.deadline {
+            clock.timers.remove(&(self.deadline, self.id));
+
+            Poll::Ready(())
+        } else {
+            clock
+                .timers
+                .insert((self.deadline, self.id), cx.waker().clone());
+
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for TestSleep {
+    fn drop(&mut self) {
+        if let Ok(mut clock) = self.clock.lock() {
+            clock.timers.remove(&(self.deadline, self.id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    use futures::StreamExt;
+
+    use crate::{Clock, Scheduler, TestScheduler};
+
+    #[test]
+    fn smoke() {
+        let scheduler = TestScheduler::new();
+        let start = scheduler.now();
+        let ticks = Rc::new(RefCell::new(Vec::new()));
+
+        scheduler.spawn({
+            let ticks = Rc::clone(&ticks);
+
+            scheduler
+                .interval(Duration::from_secs(1))
+                .take(3)
+                .for_each(move |it| {
+                    ticks.borrow_mut().push(it);
+
+                    async {}
+                })
+        });
+
+        scheduler.advance_by(Duration::from_millis(2500));
+
+        assert_eq!(*ticks.borrow(), [0, 1]);
+        assert_eq!(
+            scheduler.now().duration_since(start),
+            Duration::from_millis(2500)
+        );
+
+        scheduler.run_until_idle();
+
+        assert_eq!(*ticks.borrow(), [0, 1, 2]);
+        assert_eq!(
+            scheduler.now().duration_since(start),
+            Duration::from_secs(3)
+        );
+    }
+}
@@ -4,3 +4,9 @@ pub mod event_lite;
 pub mod notification;
 pub mod observable;
 pub mod rx;
+pub mod timer;
+pub(crate) mod waker_set;
+
+/// The maximum amount of items which are taken from a single upstream per poll,
+/// so that an upstream which is always ready cannot block the poll.
+pub(crate) const DRAIN_BUDGET: usize = 32;
@@ -1,16 +1,74 @@
-use std::{collections::VecDeque, task::Poll};
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt,
+    sync::Arc,
+    task::{Poll, Waker},
+};
+
+use crate::Event;
+
+/// Decides what happens when an event is pushed to a subscription
+/// which already buffers the maximum amount of events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowStrategy {
+    /// Drops the oldest buffered event to make room for the new event.
+    DropOldest,
+    /// Drops the new event.
+    DropNewest,
+    /// Drops the new event and terminates the subscription.
+    ///
+    /// Subjects of `Result` events which use `bounded_with_error` first emit an `Err` carrying [`Overflow`].
+    /// Otherwise, this is indistinguishable from the `Subject` closing downstream,
+    /// and only `Observable::has_overflowed` tells both apart.
+    Terminate,
+    /// Keeps buffering, but `Subject::next_async` waits until
+    /// the subscription has room again.
+    ///
+    /// The bound only holds as long as all events are pushed using `next_async`,
+    /// as `Subject::next` cannot wait, and always buffers the new event, however full the subscription is.
+    Backpressure,
+}
+
+/// The error emitted to a subscription which could not keep up with its `Subject`,
+/// when the `Subject` was created using `bounded_with_error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the subscription could not keep up with its subject")
+    }
+}
+
+impl Error for Overflow {}
+
+/// Creates the final event of a subscription which overflowed, see `bounded_with_error`.
+pub(crate) fn overflow_event<T, E: From<Overflow>>() -> Event<Result<T, E>> {
+    Event(Arc::new(Err(Overflow.into())))
+}
 
 #[derive(Clone)]
 pub struct Controller<T> {
     buffer: VecDeque<T>,
     pub(crate) is_done: bool,
+    pub(crate) has_overflowed: bool,
+    pub(crate) overflow_event: Option<fn() -> T>,
+    bounds: Option<(usize, OverflowStrategy)>,
+    waker: Option<Waker>,
+    producers: Vec<Waker>,
 }
 
 impl<T> Controller<T> {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(bounds: Option<(usize, OverflowStrategy)>) -> Self {
         Self {
             buffer: VecDeque::new(),
             is_done: false,
+            has_overflowed: false,
+            overflow_event: None,
+            bounds,
+            waker: None,
+            producers: Vec::new(),
         }
     }
 
@@ -19,12 +77,50 @@ impl<T> Controller<T> {
     }
 
     pub(crate) fn push(&mut self, value: T) {
+        if self.has_overflowed {
+            return;
+        }
+
+        if let Some((capacity, overflow)) = self.bounds {
+            if self.buffer.len() >= capacity {
+                match overflow {
+                    OverflowStrategy::DropOldest => {
+                        self.buffer.pop_front();
+                    }
+                    OverflowStrategy::DropNewest => return,
+                    OverflowStrategy::Terminate => {
+                        self.has_overflowed = true;
+
+                        if let Some(overflow_event) = self.overflow_event {
+                            self.buffer.push_back(overflow_event());
+                        }
+
+                        self.close();
+
+                        return;
+                    }
+                    OverflowStrategy::Backpressure => {}
+                }
+            }
+        }
+
         self.buffer.push_back(value);
+        self.wake();
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.is_done = true;
+        self.wake();
+        self.wake_producers();
     }
 
     pub(crate) fn pop(&mut self) -> Poll<Option<T>> {
         match self.buffer.pop_front() {
-            Some(it) => Poll::Ready(Some(it)),
+            Some(it) => {
+                self.wake_producers();
+
+                Poll::Ready(Some(it))
+            }
             None => {
                 if self.is_done {
                     Poll::Ready(None)
@@ -34,4 +130,41 @@ impl<T> Controller<T> {
             }
         }
     }
+
+    /// Stores the `Waker` of the subscriber, which is woken on the next push or close.
+    pub(crate) fn register(&mut self, waker: &Waker) {
+        match &self.waker {
+            Some(it) if it.will_wake(waker) => {}
+            _ => self.waker = Some(waker.clone()),
+        }
+    }
+
+    /// Returns `true` if a next event can be pushed without exceeding a backpressure capacity,
+    /// otherwise stores the `Waker` of the producer, which is woken on the next pop.
+    pub(crate) fn poll_ready(&mut self, waker: &Waker) -> bool {
+        match self.bounds {
+            Some((capacity, OverflowStrategy::Backpressure))
+                if !self.is_done && self.buffer.len() >= capacity =>
+            {
+                if !self.producers.iter().any(|it| it.will_wake(waker)) {
+                    self.producers.push(waker.clone());
+                }
+
+                false
+            }
+            _ => true,
+        }
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn wake_producers(&mut self) {
+        for producer in self.producers.drain(..) {
+            producer.wake();
+        }
+    }
 }
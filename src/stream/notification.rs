@@ -1,31 +1,43 @@
+use std::convert::Infallible;
+
 #[derive(Debug)]
-pub enum Notification<T> {
+pub enum Notification<T, E = Infallible> {
     Next(T),
+    Error(E),
     Complete,
 }
 
-impl<T> Notification<T> {
+impl<T, E> Notification<T, E> {
     pub fn inner_value(self) -> Option<T> {
         match self {
             Notification::Next(it) => Some(it),
-            Notification::Complete => None,
+            Notification::Error(_) | Notification::Complete => None,
+        }
+    }
+
+    pub fn inner_error(self) -> Option<E> {
+        match self {
+            Notification::Error(it) => Some(it),
+            Notification::Next(_) | Notification::Complete => None,
         }
     }
 }
 
-impl<T: PartialEq> PartialEq for Notification<T> {
+impl<T: PartialEq, E: PartialEq> PartialEq for Notification<T, E> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Next(l0), Self::Next(r0)) => l0 == r0,
+            (Self::Error(l0), Self::Error(r0)) => l0 == r0,
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
 }
 
-impl<T: Clone> Clone for Notification<T> {
+impl<T: Clone, E: Clone> Clone for Notification<T, E> {
     fn clone(&self) -> Self {
         match self {
             Self::Next(arg0) => Self::Next(arg0.clone()),
+            Self::Error(arg0) => Self::Error(arg0.clone()),
             Self::Complete => Self::Complete,
         }
     }
@@ -16,6 +16,25 @@ impl<T> Observable<T> {
     pub(crate) fn new(inner: Arc<RwLock<Controller<Event<T>>>>) -> Self {
         Self { inner }
     }
+
+    /// Returns `true` if this subscription was terminated because it could not keep up
+    /// with its `Subject`, using `OverflowStrategy::Terminate`.
+    ///
+    /// Only the `Observable` itself knows, so keep a handle to it, e.g. by polling it via `&mut`,
+    /// when the overflow needs to be told apart from a regular close.
+    pub fn has_overflowed(&self) -> bool {
+        self.inner.read().unwrap().has_overflowed
+    }
+}
+
+impl<T> Drop for Observable<T> {
+    fn drop(&mut self) {
+        // a producer waiting in `Subject::next_async` for room in this subscription
+        // would otherwise never be woken again
+        if let Ok(mut inner) = self.inner.write() {
+            inner.close();
+        }
+    }
 }
 
 impl<T> FusedStream for Observable<T> {
@@ -27,11 +46,14 @@ impl<T> FusedStream for Observable<T> {
 impl<T> Stream for Observable<T> {
     type Item = Event<T>;
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        match self.as_mut().inner.write().unwrap().pop() {
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut inner = self.inner.write().unwrap();
+
+        match inner.pop() {
             Poll::Ready(it) => Poll::Ready(it),
             Poll::Pending => {
-                cx.waker().wake_by_ref();
+                inner.register(cx.waker());
+
                 Poll::Pending
             }
         }
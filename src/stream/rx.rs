@@ -1,2 +1,14 @@
 pub mod combine_latest;
+pub mod combine_latest_all;
+pub mod concat;
+pub mod concat_all;
+pub mod fork_join;
+pub mod fork_join_all;
+pub mod merge;
+pub mod merge_all;
+pub mod race_all;
+pub mod retry;
+pub mod retry_when;
+pub mod with_latest_from;
 pub mod zip;
+pub mod zip_all;
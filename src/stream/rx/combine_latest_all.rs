@@ -0,0 +1,153 @@
+use futures::stream::{Fuse, FusedStream, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Like `CombineLatest2`..`CombineLatest9`, but for any number of `Stream`s of the same type.
+/// Emits a `Vec` containing the latest items of all `Stream`s, in the order in which they were added.
+///
+/// More `Stream`s can be added over time using `push`.
+#[must_use = "streams do nothing unless polled"]
+pub struct CombineLatestAll<S: Stream> {
+    streams: Vec<Pin<Box<Fuse<S>>>>,
+    values: Vec<Option<S::Item>>,
+}
+
+/// Creates a `CombineLatestAll` `Stream` from an iterator of `Stream`s.
+pub fn combine_latest_all<I: IntoIterator>(streams: I) -> CombineLatestAll<I::Item>
+where
+    I::Item: Stream,
+{
+    CombineLatestAll::new(streams)
+}
+
+impl<S: Stream> Unpin for CombineLatestAll<S> {}
+
+impl<S: Stream> CombineLatestAll<S> {
+    pub fn new<I: IntoIterator<Item = S>>(streams: I) -> Self {
+        let mut combine_latest_all = Self {
+            streams: Vec::new(),
+            values: Vec::new(),
+        };
+
+        for stream in streams {
+            combine_latest_all.push(stream);
+        }
+
+        combine_latest_all
+    }
+
+    /// Adds another `Stream`, no next items are emitted until this `Stream` emitted a first item.
+    pub fn push(&mut self, stream: S) {
+        self.streams.push(Box::pin(stream.fuse()));
+        self.values.push(None);
+    }
+
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+impl<S: Stream> FusedStream for CombineLatestAll<S>
+where
+    S::Item: ToOwned<Owned = S::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.streams.iter().all(|it| it.is_terminated())
+    }
+}
+
+impl<S: Stream> Stream for CombineLatestAll<S>
+where
+    S::Item: ToOwned<Owned = S::Item>,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let mut did_update_value = false;
+
+        for (stream, value) in this.streams.iter_mut().zip(this.values.iter_mut()) {
+            if !stream.is_terminated() {
+                if let Poll::Ready(Some(it)) = stream.poll_next_unpin(cx) {
+                    did_update_value = true;
+                    *value = Some(it);
+                }
+            }
+        }
+
+        if did_update_value && this.values.iter().all(|it| it.is_some()) {
+            Poll::Ready(Some(
+                this.values
+                    .iter()
+                    .flatten()
+                    .map(|it| it.to_owned())
+                    .collect(),
+            ))
+        } else if this.streams.iter().all(|it| it.is_terminated()) {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = self
+            .streams
+            .iter()
+            .map(|it| it.size_hint().0)
+            .max()
+            .unwrap_or_default();
+        let upper = if self.is_terminated() {
+            Some(lower)
+        } else {
+            None
+        };
+
+        (lower, upper)
+    }
+}
+
+#[test]
+fn test() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let stream = combine_latest_all([
+        stream::iter(vec![1, 2, 3]),
+        stream::iter(vec![6, 7, 8, 9]),
+        stream::iter(vec![0]),
+    ]);
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(
+            res,
+            [vec![1, 6, 0], vec![2, 7, 0], vec![3, 8, 0], vec![3, 9, 0]]
+        );
+    });
+}
+
+#[test]
+fn test_push() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let mut stream = CombineLatestAll::new([stream::iter(vec![1, 2])]);
+
+    block_on(async {
+        assert_eq!(stream.next().await, Some(vec![1]));
+
+        stream.push(stream::iter(vec![6, 7]));
+
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [vec![2, 6], vec![2, 7]]);
+    });
+}
@@ -0,0 +1,115 @@
+use futures::stream::{Fuse, FusedStream, Stream, StreamExt};
+use paste::paste;
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+macro_rules! concat_n {
+    ($name:ident; $($stream:ident),+) => {
+        paste! {
+            pin_project! {
+                pub struct $name<$($stream: Stream<Item = T>),+, T> {
+                    $(
+                        #[pin]
+                        [<$stream:lower>]: Fuse<$stream>,
+                    )+
+                }
+            }
+        }
+
+        impl<$($stream: Stream<Item = T>),+, T> $name<$($stream),+, T> {
+            paste! {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new($(
+                    [<$stream:lower>]: $stream),+
+                ) -> Self {
+                    $name {
+                        $(
+                            [<$stream:lower>]: [<$stream:lower>].fuse(),
+                        )+
+                    }
+                }
+            }
+        }
+
+        impl<$($stream: Stream<Item = T>),+, T> FusedStream for $name<$($stream),+, T>
+        {
+            fn is_terminated(&self) -> bool {
+                paste! {
+                    $(
+                        self.[<$stream:lower>].is_terminated()
+                    )&&+
+                }
+            }
+        }
+
+        impl<$($stream: Stream<Item = T>),+, T> Stream for $name<$($stream),+, T>
+        {
+            type Item = T;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                paste! {
+                    let streams = [$(
+                        this.[<$stream:lower>].as_mut() as Pin<&mut dyn FusedStream<Item = T>>
+                    ),+];
+                }
+
+                for mut stream in streams {
+                    if !stream.is_terminated() {
+                        match stream.as_mut().poll_next(cx) {
+                            Poll::Ready(Some(it)) => return Poll::Ready(Some(it)),
+                            Poll::Ready(None) => continue,
+                            Poll::Pending => return Poll::Pending,
+                        }
+                    }
+                }
+
+                Poll::Ready(None)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                paste! {
+                    let size_hint_all = [$(self.[<$stream:lower>].size_hint()),+];
+                }
+
+                size_hint_all
+                    .into_iter()
+                    .fold((0, Some(0)), |(lower, upper), (a, b)| {
+                        (
+                            lower.saturating_add(a),
+                            upper.zip(b).and_then(|(upper, b)| upper.checked_add(b)),
+                        )
+                    })
+            }
+        }
+    };
+}
+
+concat_n!(Concat2;S1,S2);
+concat_n!(Concat3;S1,S2,S3);
+concat_n!(Concat4;S1,S2,S3,S4);
+concat_n!(Concat5;S1,S2,S3,S4,S5);
+concat_n!(Concat6;S1,S2,S3,S4,S5,S6);
+concat_n!(Concat7;S1,S2,S3,S4,S5,S6,S7);
+concat_n!(Concat8;S1,S2,S3,S4,S5,S6,S7,S8);
+concat_n!(Concat9;S1,S2,S3,S4,S5,S6,S7,S8,S9);
+
+#[test]
+fn test() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let s1 = stream::iter([1, 2, 3]);
+    let s2 = stream::iter([6, 7, 8, 9]);
+    let s3 = stream::iter([10, 11]);
+    let stream = Concat3::new(s1, s2, s3);
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [1, 2, 3, 6, 7, 8, 9, 10, 11]);
+    });
+}
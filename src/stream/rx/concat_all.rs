@@ -0,0 +1,101 @@
+use futures::stream::{FusedStream, Stream, StreamExt};
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Like `Concat2`..`Concat9`, but for any number of `Stream`s of the same type.
+/// Emits all items of the first `Stream`, then all items of the next one, and so on.
+///
+/// More `Stream`s can be added over time using `push`.
+#[must_use = "streams do nothing unless polled"]
+pub struct ConcatAll<S: Stream> {
+    streams: VecDeque<Pin<Box<S>>>,
+}
+
+/// Creates a `ConcatAll` `Stream` from an iterator of `Stream`s.
+pub fn concat_all<I: IntoIterator>(streams: I) -> ConcatAll<I::Item>
+where
+    I::Item: Stream,
+{
+    ConcatAll::new(streams)
+}
+
+impl<S: Stream> Unpin for ConcatAll<S> {}
+
+impl<S: Stream> ConcatAll<S> {
+    pub fn new<I: IntoIterator<Item = S>>(streams: I) -> Self {
+        Self {
+            streams: streams.into_iter().map(Box::pin).collect(),
+        }
+    }
+
+    /// Adds another `Stream`, which is polled once all previous `Stream`s completed.
+    pub fn push(&mut self, stream: S) {
+        self.streams.push_back(Box::pin(stream));
+    }
+
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+impl<S: Stream> FusedStream for ConcatAll<S> {
+    fn is_terminated(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+impl<S: Stream> Stream for ConcatAll<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        while let Some(stream) = this.streams.front_mut() {
+            match stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(it)) => return Poll::Ready(Some(it)),
+                Poll::Ready(None) => {
+                    this.streams.pop_front();
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        Poll::Ready(None)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.streams
+            .iter()
+            .map(|it| it.size_hint())
+            .fold((0, Some(0)), |(lower, upper), (a, b)| {
+                (
+                    lower.saturating_add(a),
+                    upper.zip(b).and_then(|(upper, b)| upper.checked_add(b)),
+                )
+            })
+    }
+}
+
+#[test]
+fn test() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let mut stream = concat_all([stream::iter(vec![1, 2]), stream::iter(vec![6, 7])]);
+
+    block_on(async {
+        assert_eq!(stream.next().await, Some(1));
+
+        stream.push(stream::iter(vec![10, 11]));
+
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [2, 6, 7, 10, 11]);
+    });
+}
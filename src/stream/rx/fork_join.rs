@@ -0,0 +1,176 @@
+use futures::stream::{Fuse, FusedStream, Stream, StreamExt};
+use paste::paste;
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::stream::DRAIN_BUDGET;
+
+/// Drains the items which `stream` has ready, keeping only the last one in `last`,
+/// but at most a fixed amount per poll.
+///
+/// Returns `true` if `stream` may still have items ready, which were not drained yet.
+pub(crate) fn poll_last<S: Stream<Item = T>, T>(
+    mut stream: Pin<&mut Fuse<S>>,
+    last: &mut Option<T>,
+    cx: &mut Context<'_>,
+) -> bool {
+    for _ in 0..DRAIN_BUDGET {
+        match stream.as_mut().poll_next(cx) {
+            Poll::Ready(Some(it)) => *last = Some(it),
+            _ => return false,
+        }
+    }
+
+    !stream.is_terminated()
+}
+
+macro_rules! fork_join {
+    ($name:ident; $($stream:ident),+; $($type:ident),+) => {
+        paste! {
+            pin_project! {
+                pub struct $name<$($stream: Stream<Item = $type>),+, $($type),+> {
+                    $(
+                        #[pin]
+                        [<$stream:lower>]: Fuse<$stream>,
+                        [<$type:lower>]: Option<$type>,
+                    )+
+                    is_done: bool,
+                }
+            }
+        }
+
+        impl<$($stream: Stream<Item = $type>),+, $($type),+> $name<$($stream),+, $($type),+> {
+            paste! {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new($(
+                    [<$stream:lower>]: $stream),+
+                ) -> Self {
+                    $name {
+                        $(
+                            [<$stream:lower>]: [<$stream:lower>].fuse(),
+                            [<$type:lower>]: None,
+                        )+
+                        is_done: false,
+                    }
+                }
+            }
+        }
+
+        impl<$($stream: Stream<Item = $type>),+, $($type),+> FusedStream for $name<$($stream),+, $($type),+>
+        {
+            fn is_terminated(&self) -> bool {
+                self.is_done
+            }
+        }
+
+        impl<$($stream: Stream<Item = $type>),+, $($type),+> Stream for $name<$($stream),+, $($type),+>
+        {
+            type Item = ($($type),+);
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                if *this.is_done {
+                    return Poll::Ready(None);
+                }
+
+                paste! {
+                    let mut is_exhausted = false;
+                    $(
+                        if !this.[<$stream:lower>].is_terminated()
+                            && poll_last(this.[<$stream:lower>].as_mut(), this.[<$type:lower>], cx)
+                        {
+                            is_exhausted = true;
+                        }
+                    )+
+
+                    if $((this.[<$stream:lower>].is_terminated() && this.[<$type:lower>].is_none()))||+ {
+                        *this.is_done = true;
+
+                        Poll::Ready(None)
+                    } else if $(this.[<$stream:lower>].is_terminated())&&+ {
+                        *this.is_done = true;
+
+                        Poll::Ready(Some((
+                            $(
+                                this.[<$type:lower>].take().unwrap()
+                            ),+
+                        )))
+                    } else {
+                        if is_exhausted {
+                            cx.waker().wake_by_ref();
+                        }
+
+                        Poll::Pending
+                    }
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.is_done {
+                    (0, Some(0))
+                } else {
+                    (0, Some(1))
+                }
+            }
+        }
+    };
+}
+
+fork_join!(ForkJoin2;S1,S2;T1,T2);
+fork_join!(ForkJoin3;S1,S2,S3;T1,T2,T3);
+fork_join!(ForkJoin4;S1,S2,S3,S4;T1,T2,T3,T4);
+fork_join!(ForkJoin5;S1,S2,S3,S4,S5;T1,T2,T3,T4,T5);
+fork_join!(ForkJoin6;S1,S2,S3,S4,S5,S6;T1,T2,T3,T4,T5,T6);
+fork_join!(ForkJoin7;S1,S2,S3,S4,S5,S6,S7;T1,T2,T3,T4,T5,T6,T7);
+fork_join!(ForkJoin8;S1,S2,S3,S4,S5,S6,S7,S8;T1,T2,T3,T4,T5,T6,T7,T8);
+fork_join!(ForkJoin9;S1,S2,S3,S4,S5,S6,S7,S8,S9;T1,T2,T3,T4,T5,T6,T7,T8,T9);
+
+#[test]
+fn test() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let s1 = stream::iter([1, 2, 3]);
+    let s2 = stream::iter([6, 7, 8, 9]);
+    let s3 = stream::iter(["a", "b"]);
+    let stream = ForkJoin3::new(s1, s2, s3);
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [(3, 9, "b")]);
+    });
+}
+
+#[test]
+fn test_always_ready() {
+    use futures::stream::{self};
+    use futures::{FutureExt, StreamExt};
+
+    let s1 = stream::repeat(1);
+    let s2 = stream::iter([6, 7, 8, 9]);
+    let mut stream = ForkJoin2::new(s1, s2);
+
+    // always pending, but returns control to the executor
+    assert!(stream.next().now_or_never().is_none());
+}
+
+#[test]
+fn test_empty() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let s1 = stream::iter([1, 2, 3]);
+    let s2 = stream::iter(Vec::<i32>::new());
+    let stream = ForkJoin2::new(s1, s2);
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, []);
+    });
+}
@@ -0,0 +1,148 @@
+use futures::stream::{Fuse, FusedStream, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use super::fork_join::poll_last;
+
+/// Like `ForkJoin2`..`ForkJoin9`, but for any number of `Stream`s of the same type.
+/// Waits for all `Stream`s to complete, then emits a single `Vec` containing the last item of each `Stream`,
+/// in the order in which they were added.
+///
+/// Completes without emitting if any `Stream` completes without emitting, or if there are no `Stream`s at all.
+#[must_use = "streams do nothing unless polled"]
+pub struct ForkJoinAll<S: Stream> {
+    streams: Vec<Pin<Box<Fuse<S>>>>,
+    values: Vec<Option<S::Item>>,
+    is_done: bool,
+}
+
+/// Creates a `ForkJoinAll` `Stream` from an iterator of `Stream`s.
+pub fn fork_join_all<I: IntoIterator>(streams: I) -> ForkJoinAll<I::Item>
+where
+    I::Item: Stream,
+{
+    ForkJoinAll::new(streams)
+}
+
+impl<S: Stream> Unpin for ForkJoinAll<S> {}
+
+impl<S: Stream> ForkJoinAll<S> {
+    pub fn new<I: IntoIterator<Item = S>>(streams: I) -> Self {
+        let streams = streams
+            .into_iter()
+            .map(|it| Box::pin(it.fuse()))
+            .collect::<Vec<_>>();
+        let values = streams.iter().map(|_| None).collect();
+
+        Self {
+            streams,
+            values,
+            is_done: false,
+        }
+    }
+}
+
+impl<S: Stream> FusedStream for ForkJoinAll<S> {
+    fn is_terminated(&self) -> bool {
+        self.is_done
+    }
+}
+
+impl<S: Stream> Stream for ForkJoinAll<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.is_done {
+            return Poll::Ready(None);
+        }
+
+        let mut is_exhausted = false;
+
+        for (stream, value) in this.streams.iter_mut().zip(this.values.iter_mut()) {
+            if !stream.is_terminated() && poll_last(stream.as_mut(), value, cx) {
+                is_exhausted = true;
+            }
+        }
+
+        let mut states = this.streams.iter().zip(this.values.iter());
+
+        if this.streams.is_empty()
+            || states.any(|(stream, value)| stream.is_terminated() && value.is_none())
+        {
+            this.is_done = true;
+
+            Poll::Ready(None)
+        } else if this.streams.iter().all(|it| it.is_terminated()) {
+            this.is_done = true;
+
+            Poll::Ready(Some(
+                this.values.iter_mut().flat_map(|it| it.take()).collect(),
+            ))
+        } else {
+            // streams may still have items ready, which were not drained yet
+            if is_exhausted {
+                cx.waker().wake_by_ref();
+            }
+
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_done {
+            (0, Some(0))
+        } else {
+            (0, Some(1))
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let stream = fork_join_all([
+        stream::iter(vec![1, 2, 3]),
+        stream::iter(vec![6, 7, 8, 9]),
+        stream::iter(vec![0]),
+    ]);
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [vec![3, 9, 0]]);
+    });
+}
+
+#[test]
+fn test_always_ready() {
+    use futures::stream::{self};
+    use futures::{FutureExt, StreamExt};
+
+    let mut stream = fork_join_all([
+        stream::iter(vec![1, 2, 3]).boxed(),
+        stream::repeat(1).boxed(),
+    ]);
+
+    // always pending, but returns control to the executor
+    assert!(stream.next().now_or_never().is_none());
+}
+
+#[test]
+fn test_empty() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let stream = fork_join_all([stream::iter(vec![1, 2, 3]), stream::iter(vec![])]);
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, Vec::<Vec<i32>>::new());
+    });
+}
@@ -0,0 +1,128 @@
+use futures::stream::{Fuse, FusedStream, Stream, StreamExt};
+use paste::paste;
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+macro_rules! merge {
+    ($name:ident; $($stream:ident),+) => {
+        paste! {
+            pin_project! {
+                pub struct $name<$($stream: Stream<Item = T>),+, T> {
+                    $(
+                        #[pin]
+                        [<$stream:lower>]: Fuse<$stream>,
+                    )+
+                    index: usize,
+                }
+            }
+        }
+
+        impl<$($stream: Stream<Item = T>),+, T> $name<$($stream),+, T> {
+            paste! {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new($(
+                    [<$stream:lower>]: $stream),+
+                ) -> Self {
+                    $name {
+                        $(
+                            [<$stream:lower>]: [<$stream:lower>].fuse(),
+                        )+
+                        index: 0,
+                    }
+                }
+            }
+        }
+
+        impl<$($stream: Stream<Item = T>),+, T> FusedStream for $name<$($stream),+, T>
+        {
+            fn is_terminated(&self) -> bool {
+                paste! {
+                    $(
+                        self.[<$stream:lower>].is_terminated()
+                    )&&+
+                }
+            }
+        }
+
+        impl<$($stream: Stream<Item = T>),+, T> Stream for $name<$($stream),+, T>
+        {
+            type Item = T;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                paste! {
+                    let mut streams = [$(
+                        this.[<$stream:lower>].as_mut() as Pin<&mut dyn FusedStream<Item = T>>
+                    ),+];
+                }
+
+                let len = streams.len();
+
+                // start polling at the stream after the one which emitted last,
+                // so that a busy stream cannot starve the others
+                for offset in 0..len {
+                    let index = (*this.index + offset) % len;
+                    let stream = &mut streams[index];
+
+                    if !stream.is_terminated() {
+                        if let Poll::Ready(Some(it)) = stream.as_mut().poll_next(cx) {
+                            *this.index = (index + 1) % len;
+
+                            return Poll::Ready(Some(it));
+                        }
+                    }
+                }
+
+                if streams.iter().all(|it| it.is_terminated()) {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                paste! {
+                    let size_hint_all = [$(self.[<$stream:lower>].size_hint()),+];
+                }
+
+                size_hint_all
+                    .into_iter()
+                    .fold((0, Some(0)), |(lower, upper), (a, b)| {
+                        (
+                            lower.saturating_add(a),
+                            upper.zip(b).and_then(|(upper, b)| upper.checked_add(b)),
+                        )
+                    })
+            }
+        }
+    };
+}
+
+merge!(Merge2;S1,S2);
+merge!(Merge3;S1,S2,S3);
+merge!(Merge4;S1,S2,S3,S4);
+merge!(Merge5;S1,S2,S3,S4,S5);
+merge!(Merge6;S1,S2,S3,S4,S5,S6);
+merge!(Merge7;S1,S2,S3,S4,S5,S6,S7);
+merge!(Merge8;S1,S2,S3,S4,S5,S6,S7,S8);
+merge!(Merge9;S1,S2,S3,S4,S5,S6,S7,S8,S9);
+
+#[test]
+fn test() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let s1 = stream::iter([1, 2, 3]);
+    let s2 = stream::iter([6, 7, 8, 9]);
+    let s3 = stream::iter([10, 11]);
+    let stream = Merge3::new(s1, s2, s3);
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [1, 6, 10, 2, 7, 11, 3, 8, 9]);
+    });
+}
@@ -0,0 +1,128 @@
+use futures::stream::{FusedStream, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Like `Merge2`..`Merge9`, but for any number of `Stream`s of the same type.
+/// Emits items from all `Stream`s as they arrive, and completes when all `Stream`s completed.
+///
+/// More `Stream`s can be added over time using `push`, completed `Stream`s are dropped.
+#[must_use = "streams do nothing unless polled"]
+pub struct MergeAll<S: Stream> {
+    streams: Vec<Pin<Box<S>>>,
+    index: usize,
+}
+
+/// Creates a `MergeAll` `Stream` from an iterator of `Stream`s.
+pub fn merge_all<I: IntoIterator>(streams: I) -> MergeAll<I::Item>
+where
+    I::Item: Stream,
+{
+    MergeAll::new(streams)
+}
+
+impl<S: Stream> Unpin for MergeAll<S> {}
+
+impl<S: Stream> MergeAll<S> {
+    pub fn new<I: IntoIterator<Item = S>>(streams: I) -> Self {
+        Self {
+            streams: streams.into_iter().map(Box::pin).collect(),
+            index: 0,
+        }
+    }
+
+    /// Adds another `Stream`, its items are merged in from now on.
+    pub fn push(&mut self, stream: S) {
+        self.streams.push(Box::pin(stream));
+    }
+
+    /// Returns the amount of `Stream`s which have not completed yet.
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+impl<S: Stream> FusedStream for MergeAll<S> {
+    fn is_terminated(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+impl<S: Stream> Stream for MergeAll<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let mut index = this.index;
+
+        for _ in 0..this.streams.len() {
+            index %= this.streams.len();
+
+            match this.streams[index].poll_next_unpin(cx) {
+                Poll::Ready(Some(it)) => {
+                    this.index = index + 1;
+
+                    return Poll::Ready(Some(it));
+                }
+                Poll::Ready(None) => {
+                    this.streams.swap_remove(index);
+                }
+                Poll::Pending => index += 1,
+            }
+        }
+
+        this.index = index;
+
+        if this.streams.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.streams
+            .iter()
+            .map(|it| it.size_hint())
+            .fold((0, Some(0)), |(lower, upper), (a, b)| {
+                (
+                    lower.saturating_add(a),
+                    upper.zip(b).and_then(|(upper, b)| upper.checked_add(b)),
+                )
+            })
+    }
+}
+
+#[test]
+fn test() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let mut stream = merge_all([stream::iter(vec![1, 2, 3]), stream::iter(vec![6, 7, 8, 9])]);
+
+    block_on(async {
+        assert_eq!(stream.next().await, Some(1));
+
+        stream.push(stream::iter(vec![10, 11]));
+
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [6, 10, 2, 7, 11, 3, 8, 9]);
+    });
+
+    let mut stream = merge_all([stream::iter(vec![1]), stream::iter(vec![2, 3])]);
+
+    block_on(async {
+        assert_eq!(stream.len(), 2);
+        assert_eq!(stream.next().await, Some(1));
+        assert_eq!(stream.next().await, Some(2));
+        assert_eq!(stream.next().await, Some(3));
+        assert_eq!(stream.len(), 1);
+        assert_eq!(stream.next().await, None);
+        assert!(stream.is_empty());
+    });
+}
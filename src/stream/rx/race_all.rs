@@ -0,0 +1,118 @@
+use futures::stream::{Fuse, FusedStream, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Like [`race`](crate::RxExt::race), but for any number of `Stream`s of the same type.
+/// The first `Stream` to emit an item or to complete wins, all other `Stream`s are then dropped
+/// and only the items of the winner are emitted from there on.
+///
+/// Completes immediately if there are no `Stream`s at all.
+#[must_use = "streams do nothing unless polled"]
+pub struct RaceAll<S: Stream> {
+    streams: Vec<Pin<Box<Fuse<S>>>>,
+    has_winner: bool,
+}
+
+/// Creates a `RaceAll` `Stream` from an iterator of `Stream`s.
+pub fn race_all<I: IntoIterator>(streams: I) -> RaceAll<I::Item>
+where
+    I::Item: Stream,
+{
+    RaceAll::new(streams)
+}
+
+impl<S: Stream> RaceAll<S> {
+    pub fn new<I: IntoIterator<Item = S>>(streams: I) -> Self {
+        Self {
+            streams: streams.into_iter().map(|it| Box::pin(it.fuse())).collect(),
+            has_winner: false,
+        }
+    }
+}
+
+impl<S: Stream> FusedStream for RaceAll<S> {
+    fn is_terminated(&self) -> bool {
+        self.streams.iter().all(|it| it.is_terminated())
+    }
+}
+
+impl<S: Stream> Stream for RaceAll<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.has_winner {
+            return this.streams[0].poll_next_unpin(cx);
+        }
+
+        for index in 0..this.streams.len() {
+            // like `race`, a `Stream` which completes first also wins, which completes the race
+            if let Poll::Ready(it) = this.streams[index].poll_next_unpin(cx) {
+                let winner = this.streams.swap_remove(index);
+
+                this.streams.clear();
+                this.streams.push(winner);
+                this.has_winner = true;
+
+                return Poll::Ready(it);
+            }
+        }
+
+        if this.streams.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.has_winner {
+            self.streams[0].size_hint()
+        } else {
+            let lower = self
+                .streams
+                .iter()
+                .map(|it| it.size_hint().0)
+                .min()
+                .unwrap_or_default();
+            let upper = self
+                .streams
+                .iter()
+                .map(|it| it.size_hint().1)
+                .try_fold(0, |upper, it| it.map(|it| upper.max(it)));
+
+            (lower, upper)
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::marble::{assert_marbles, cold};
+    use crate::TestScheduler;
+
+    let scheduler = TestScheduler::new();
+    let stream = race_all([
+        cold("---abc|", &scheduler),
+        cold("-de-f|", &scheduler),
+        cold("--gh|", &scheduler),
+    ]);
+
+    assert_marbles(&scheduler, stream, "-de-f|");
+}
+
+#[test]
+fn test_completion_wins() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let stream = race_all([stream::iter(vec![]), stream::iter(vec![4, 5])]);
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, []);
+    });
+}
@@ -0,0 +1,125 @@
+use futures::stream::{FusedStream, Stream};
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+pin_project! {
+    /// Stream for the [`retry`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Retry<F, S> {
+        factory: F,
+        #[pin]
+        stream: S,
+        retries_left: usize,
+        is_done: bool,
+    }
+}
+
+/// Subscribes to the `Stream` created by `factory`, and whenever that `Stream` yields an `Err`,
+/// drops it and subscribes to a new `Stream` created by `factory` instead, up to `count` times.
+/// Once all retries are used up, the last `Err` is emitted and the `Stream` completes.
+pub fn retry<F, S, T, E>(mut factory: F, count: usize) -> Retry<F, S>
+where
+    F: FnMut() -> S,
+    S: Stream<Item = Result<T, E>>,
+{
+    let stream = factory();
+
+    Retry {
+        factory,
+        stream,
+        retries_left: count,
+        is_done: false,
+    }
+}
+
+impl<F, S, T, E> FusedStream for Retry<F, S>
+where
+    F: FnMut() -> S,
+    S: Stream<Item = Result<T, E>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.is_done
+    }
+}
+
+impl<F, S, T, E> Stream for Retry<F, S>
+where
+    F: FnMut() -> S,
+    S: Stream<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.is_done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(Err(_)) if *this.retries_left > 0 => {
+                    *this.retries_left -= 1;
+                    this.stream.set((this.factory)());
+                }
+                Some(Err(e)) => {
+                    *this.is_done = true;
+
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Some(Ok(it)) => return Poll::Ready(Some(Ok(it))),
+                None => {
+                    *this.is_done = true;
+
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_done {
+            (0, Some(0))
+        } else {
+            (self.stream.size_hint().0, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use super::retry;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let mut attempt = 0;
+            let all_events = retry(
+                || {
+                    attempt += 1;
+
+                    stream::iter([Ok(attempt), Err("failed")])
+                },
+                2,
+            )
+            .collect::<Vec<_>>()
+            .await;
+
+            assert_eq!(all_events, [Ok(1), Ok(2), Ok(3), Err("failed")]);
+        });
+    }
+
+    #[test]
+    fn completes_without_error() {
+        block_on(async {
+            let all_events = retry(|| stream::iter([Ok::<_, ()>(1), Ok(2)]), 2)
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [Ok(1), Ok(2)]);
+        });
+    }
+}
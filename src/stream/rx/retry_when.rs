@@ -0,0 +1,193 @@
+use futures::stream::{FusedStream, Stream};
+use futures::Future;
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+pin_project! {
+    /// Stream for the [`retry_when`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct RetryWhen<F, S, N, Fut> {
+        factory: F,
+        notifier: N,
+        #[pin]
+        stream: S,
+        #[pin]
+        pending: Option<Fut>,
+        attempt: usize,
+        is_done: bool,
+    }
+}
+
+/// Subscribes to the `Stream` created by `factory`, and whenever that `Stream` yields an `Err`,
+/// passes the error and the attempt count, starting at 1, to `notifier`.
+///
+/// If the `Future` returned by `notifier` resolves to `Ok`, then a new `Stream` is created by `factory` and subscribed to,
+/// if it resolves to `Err`, then that error is emitted and the `Stream` completes.
+/// Use the `Future` to delay retrying, for example to back off exponentially.
+pub fn retry_when<F, S, N, Fut, T, E>(mut factory: F, notifier: N) -> RetryWhen<F, S, N, Fut>
+where
+    F: FnMut() -> S,
+    S: Stream<Item = Result<T, E>>,
+    N: FnMut(E, usize) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+{
+    let stream = factory();
+
+    RetryWhen {
+        factory,
+        notifier,
+        stream,
+        pending: None,
+        attempt: 0,
+        is_done: false,
+    }
+}
+
+impl<F, S, N, Fut, T, E> FusedStream for RetryWhen<F, S, N, Fut>
+where
+    F: FnMut() -> S,
+    S: Stream<Item = Result<T, E>>,
+    N: FnMut(E, usize) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.is_done
+    }
+}
+
+impl<F, S, N, Fut, T, E> Stream for RetryWhen<F, S, N, Fut>
+where
+    F: FnMut() -> S,
+    S: Stream<Item = Result<T, E>>,
+    N: FnMut(E, usize) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.is_done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            if let Some(pending) = this.pending.as_mut().as_pin_mut() {
+                let result = ready!(pending.poll(cx));
+
+                this.pending.set(None);
+
+                match result {
+                    Ok(()) => {
+                        this.stream.set((this.factory)());
+
+                        // yields after every resubscribe, so that a `Stream` which keeps failing
+                        // right away cannot block this poll
+                        cx.waker().wake_by_ref();
+
+                        return Poll::Pending;
+                    }
+                    Err(e) => {
+                        *this.is_done = true;
+
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+            }
+
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(Err(e)) => {
+                    *this.attempt += 1;
+                    this.pending.set(Some((this.notifier)(e, *this.attempt)));
+                }
+                Some(Ok(it)) => return Poll::Ready(Some(Ok(it))),
+                None => {
+                    *this.is_done = true;
+
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_done {
+            (0, Some(0))
+        } else if self.pending.is_some() {
+            (0, None)
+        } else {
+            (self.stream.size_hint().0, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use futures::{executor::block_on, future, stream, FutureExt, StreamExt};
+
+    use super::retry_when;
+    use crate::{
+        marble::{assert_try_marbles, cold_try},
+        Scheduler, TestScheduler,
+    };
+
+    #[test]
+    fn smoke() {
+        let scheduler = TestScheduler::new();
+        let stream = retry_when(
+            {
+                let scheduler = scheduler.clone();
+
+                move || cold_try("a#", &scheduler)
+            },
+            {
+                let scheduler = scheduler.clone();
+
+                move |e, attempt| {
+                    let sleep = scheduler.sleep(Duration::from_millis(attempt as u64));
+
+                    async move {
+                        if attempt < 3 {
+                            sleep.await;
+
+                            Ok(())
+                        } else {
+                            Err(e)
+                        }
+                    }
+                }
+            },
+        );
+
+        // retries after 1ms and 2ms, then gives up
+        assert_try_marbles(&scheduler, stream, "a-a--a#");
+    }
+
+    #[test]
+    fn yields_between_attempts() {
+        let mut stream = retry_when(
+            || stream::iter([Err::<(), _>("failed")]),
+            |_, _| future::ready(Ok(())),
+        );
+
+        // always pending, but returns control to the executor
+        assert!(stream.next().now_or_never().is_none());
+    }
+
+    #[test]
+    fn can_replace_error() {
+        block_on(async {
+            let all_events = retry_when(
+                || stream::iter([Ok(1), Err("failed")]),
+                |_, _| future::ready(Err("gave up")),
+            )
+            .collect::<Vec<_>>()
+            .await;
+
+            assert_eq!(all_events, [Ok(1), Err("gave up")]);
+        });
+    }
+}
@@ -0,0 +1,207 @@
+use futures::stream::{Fuse, FusedStream, Stream, StreamExt};
+use paste::paste;
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::stream::DRAIN_BUDGET;
+
+macro_rules! with_latest_from {
+    ($name:ident; $primary:ident, $($stream:ident),+; $primary_type:ident, $($type:ident),+) => {
+        paste! {
+            pin_project! {
+                pub struct $name<$primary: Stream<Item = $primary_type>, $($stream: Stream<Item = $type>),+, $primary_type, $($type),+> {
+                    #[pin]
+                    [<$primary:lower>]: Fuse<$primary>,
+                    $(
+                        #[pin]
+                        [<$stream:lower>]: Fuse<$stream>,
+                        [<$type:lower>]: Option<$type>,
+                    )+
+                }
+            }
+        }
+
+        impl<$primary: Stream<Item = $primary_type>, $($stream: Stream<Item = $type>),+, $primary_type, $($type),+> $name<$primary, $($stream),+, $primary_type, $($type),+> {
+            paste! {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    [<$primary:lower>]: $primary,
+                    $(
+                        [<$stream:lower>]: $stream
+                    ),+
+                ) -> Self {
+                    $name {
+                        [<$primary:lower>]: [<$primary:lower>].fuse(),
+                        $(
+                            [<$stream:lower>]: [<$stream:lower>].fuse(),
+                            [<$type:lower>]: None,
+                        )+
+                    }
+                }
+            }
+        }
+
+        impl<$primary: Stream<Item = $primary_type>, $($stream: Stream<Item = $type>),+, $primary_type, $($type: ToOwned<Owned = $type>),+> FusedStream for $name<$primary, $($stream),+, $primary_type, $($type),+>
+        {
+            fn is_terminated(&self) -> bool {
+                paste! {
+                    self.[<$primary:lower>].is_terminated()
+                }
+            }
+        }
+
+        impl<$primary: Stream<Item = $primary_type>, $($stream: Stream<Item = $type>),+, $primary_type, $($type: ToOwned<Owned = $type>),+> Stream for $name<$primary, $($stream),+, $primary_type, $($type),+>
+        {
+            type Item = ($primary_type, $($type),+);
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                paste! {
+                    // drain all other streams first, so that the primary event is paired with their latest items
+                    let mut is_exhausted = false;
+                    $(
+                        let mut budget = DRAIN_BUDGET;
+
+                        while !this.[<$stream:lower>].is_terminated() {
+                            if budget == 0 {
+                                is_exhausted = true;
+                                break;
+                            }
+
+                            budget -= 1;
+
+                            match this.[<$stream:lower>].as_mut().poll_next(cx) {
+                                Poll::Ready(Some(it)) => *this.[<$type:lower>] = Some(it),
+                                _ => break,
+                            }
+                        }
+                    )+
+
+                    match this.[<$primary:lower>].as_mut().poll_next(cx) {
+                        Poll::Ready(Some(event)) => {
+                            if $(this.[<$type:lower>].is_some())&&+ {
+                                Poll::Ready(Some((
+                                    event,
+                                    $(
+                                        this.[<$type:lower>].as_ref().unwrap().to_owned()
+                                    ),+
+                                )))
+                            } else {
+                                // events are dropped until all other streams emitted at least once
+                                cx.waker().wake_by_ref();
+
+                                Poll::Pending
+                            }
+                        }
+                        Poll::Ready(None) => Poll::Ready(None),
+                        Poll::Pending => {
+                            // other streams may still have items ready, which were not drained yet
+                            if is_exhausted {
+                                cx.waker().wake_by_ref();
+                            }
+
+                            Poll::Pending
+                        }
+                    }
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                paste! {
+                    let (_, upper) = self.[<$primary:lower>].size_hint();
+                }
+
+                (0, upper)
+            }
+        }
+    };
+}
+
+with_latest_from!(WithLatestFrom2;S1,S2;T1,T2);
+with_latest_from!(WithLatestFrom3;S1,S2,S3;T1,T2,T3);
+with_latest_from!(WithLatestFrom4;S1,S2,S3,S4;T1,T2,T3,T4);
+with_latest_from!(WithLatestFrom5;S1,S2,S3,S4,S5;T1,T2,T3,T4,T5);
+with_latest_from!(WithLatestFrom6;S1,S2,S3,S4,S5,S6;T1,T2,T3,T4,T5,T6);
+with_latest_from!(WithLatestFrom7;S1,S2,S3,S4,S5,S6,S7;T1,T2,T3,T4,T5,T6,T7);
+with_latest_from!(WithLatestFrom8;S1,S2,S3,S4,S5,S6,S7,S8;T1,T2,T3,T4,T5,T6,T7,T8);
+with_latest_from!(WithLatestFrom9;S1,S2,S3,S4,S5,S6,S7,S8,S9;T1,T2,T3,T4,T5,T6,T7,T8,T9);
+
+#[test]
+fn test() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let mut phase = 0usize;
+    let s1 = stream::poll_fn(move |cx| {
+        phase += 1;
+
+        match phase {
+            1 | 3 => Poll::Ready(Some(phase)),
+            2 => {
+                cx.waker().wake_by_ref();
+
+                Poll::Pending
+            }
+            _ => Poll::Ready(None),
+        }
+    });
+    let s2 = stream::iter([6, 7, 8, 9]);
+    let s3 = stream::iter([0]);
+    let stream = WithLatestFrom3::new(s1, s2, s3);
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [(1, 9, 0), (3, 9, 0)]);
+    });
+}
+
+#[test]
+fn test_latest() {
+    use crate::{PublishSubject, Subject};
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    let primary = PublishSubject::new();
+    let secondary = PublishSubject::new();
+    let mut stream = WithLatestFrom2::new(
+        primary.subscribe().map(|it| *it),
+        secondary.subscribe().map(|it| *it),
+    );
+
+    block_on(async {
+        for i in 0..=4 {
+            secondary.next(i);
+        }
+
+        primary.next(100);
+
+        assert_eq!(stream.next().await, Some((100, 4)));
+
+        secondary.next(5);
+        secondary.next(6);
+        primary.next(200);
+        primary.close();
+
+        assert_eq!(stream.next().await, Some((200, 6)));
+        assert_eq!(stream.next().await, None);
+    });
+}
+
+#[test]
+fn test_always_ready() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let stream = WithLatestFrom2::new(stream::iter(0..3), stream::repeat(1));
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [(0, 1), (1, 1), (2, 1)]);
+    });
+}
@@ -0,0 +1,134 @@
+use futures::stream::{Fuse, FusedStream, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Like `Zip2`..`Zip9`, but for any number of `Stream`s of the same type.
+/// Emits a `Vec` containing the next items of all `Stream`s, in the order in which they were added.
+///
+/// More `Stream`s can be added over time using `push`.
+#[must_use = "streams do nothing unless polled"]
+pub struct ZipAll<S: Stream> {
+    streams: Vec<Pin<Box<Fuse<S>>>>,
+    values: Vec<Option<S::Item>>,
+}
+
+/// Creates a `ZipAll` `Stream` from an iterator of `Stream`s.
+pub fn zip_all<I: IntoIterator>(streams: I) -> ZipAll<I::Item>
+where
+    I::Item: Stream,
+{
+    ZipAll::new(streams)
+}
+
+impl<S: Stream> Unpin for ZipAll<S> {}
+
+impl<S: Stream> ZipAll<S> {
+    pub fn new<I: IntoIterator<Item = S>>(streams: I) -> Self {
+        let mut zip_all = Self {
+            streams: Vec::new(),
+            values: Vec::new(),
+        };
+
+        for stream in streams {
+            zip_all.push(stream);
+        }
+
+        zip_all
+    }
+
+    /// Adds another `Stream`, no next items are emitted until this `Stream` emitted its next item.
+    pub fn push(&mut self, stream: S) {
+        self.streams.push(Box::pin(stream.fuse()));
+        self.values.push(None);
+    }
+
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+impl<S: Stream> FusedStream for ZipAll<S> {
+    fn is_terminated(&self) -> bool {
+        self.streams.is_empty() || self.streams.iter().any(|it| it.is_terminated())
+    }
+}
+
+impl<S: Stream> Stream for ZipAll<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        for (stream, value) in this.streams.iter_mut().zip(this.values.iter_mut()) {
+            if !stream.is_terminated() && value.is_none() {
+                if let Poll::Ready(Some(it)) = stream.poll_next_unpin(cx) {
+                    *value = Some(it);
+                }
+            }
+        }
+
+        if !this.values.is_empty() && this.values.iter().all(|it| it.is_some()) {
+            Poll::Ready(Some(
+                this.values.iter_mut().flat_map(|it| it.take()).collect(),
+            ))
+        } else if this.is_terminated() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = self
+            .streams
+            .iter()
+            .map(|it| it.size_hint().0)
+            .min()
+            .unwrap_or_default();
+        let upper = self.streams.iter().filter_map(|it| it.size_hint().1).min();
+
+        (lower, upper)
+    }
+}
+
+#[test]
+fn test() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let stream = zip_all([
+        stream::iter(vec![1, 2, 3]),
+        stream::iter(vec![6, 7, 8, 9]),
+        stream::iter(vec![10, 11, 12]),
+    ]);
+
+    block_on(async {
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [vec![1, 6, 10], vec![2, 7, 11], vec![3, 8, 12]]);
+    });
+}
+
+#[test]
+fn test_push() {
+    use futures::executor::block_on;
+    use futures::stream::{self};
+    use futures::StreamExt;
+
+    let mut stream = ZipAll::new([stream::iter(vec![1, 2, 3])]);
+
+    block_on(async {
+        assert_eq!(stream.next().await, Some(vec![1]));
+
+        stream.push(stream::iter(vec![6, 7]));
+
+        let res = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(res, [vec![2, 6], vec![3, 7]]);
+    });
+}
@@ -0,0 +1,160 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::{stream::FusedStream, Stream};
+use pin_project_lite::pin_project;
+
+use crate::Scheduler;
+
+pin_project! {
+    /// Stream for the [`timer`] and [`interval`] functions.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Timer<Sch: Scheduler> {
+        scheduler: Sch,
+        due: Duration,
+        period: Option<Duration>,
+        deadline: Option<Instant>,
+        count: usize,
+        #[pin]
+        sleep: Option<Sch::Sleep>,
+        is_done: bool,
+    }
+}
+
+/// Creates a `Stream` which emits `0` once `due` has passed, and then completes.
+/// If a `period` is provided, it instead keeps emitting the next count for every `period` which follows.
+///
+/// Time starts when the `Stream` is first polled.
+pub fn timer<Sch: Scheduler>(
+    due: Duration,
+    period: Option<Duration>,
+    scheduler: &Sch,
+) -> Timer<Sch> {
+    Timer {
+        scheduler: scheduler.clone(),
+        due,
+        period,
+        deadline: None,
+        count: 0,
+        sleep: None,
+        is_done: false,
+    }
+}
+
+/// Creates a `Stream` which emits an incrementing count, starting at `0`, for every `period` which passes.
+///
+/// Time starts when the `Stream` is first polled.
+pub fn interval<Sch: Scheduler>(period: Duration, scheduler: &Sch) -> Timer<Sch> {
+    timer(period, Some(period), scheduler)
+}
+
+impl<Sch: Scheduler> FusedStream for Timer<Sch> {
+    fn is_terminated(&self) -> bool {
+        self.is_done
+    }
+}
+
+impl<Sch: Scheduler> Stream for Timer<Sch> {
+    type Item = usize;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.is_done {
+            return Poll::Ready(None);
+        }
+
+        if this.sleep.is_none() {
+            let deadline = match *this.deadline {
+                Some(deadline) => deadline + this.period.unwrap_or_default(),
+                None => this.scheduler.now() + *this.due,
+            };
+
+            *this.deadline = Some(deadline);
+            this.sleep.set(Some(this.scheduler.sleep_until(deadline)));
+        }
+
+        if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+            ready!(sleep.poll(cx));
+        }
+
+        let count = *this.count;
+
+        this.sleep.set(None);
+        *this.count += 1;
+        *this.is_done = this.period.is_none();
+
+        Poll::Ready(Some(count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.is_done, self.period) {
+            (true, _) => (0, Some(0)),
+            (false, None) => (1, Some(1)),
+            (false, Some(_)) => (usize::MAX, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, TestScheduler,
+    };
+
+    use super::{interval, timer};
+
+    fn to_char(count: usize) -> char {
+        char::from_digit(count as u32, 10).unwrap()
+    }
+
+    #[test]
+    fn smoke() {
+        let scheduler = TestScheduler::new();
+        let stream = interval(Duration::from_millis(2), &scheduler)
+            .take(4)
+            .map(to_char);
+
+        assert_marbles(&scheduler, stream, "--0-1-2-(3|)");
+    }
+
+    #[test]
+    fn timer_once() {
+        let scheduler = TestScheduler::new();
+        let stream = timer(Duration::from_millis(3), None, &scheduler).map(to_char);
+
+        assert_marbles(&scheduler, stream, "---(0|)");
+    }
+
+    #[test]
+    fn timer_with_period() {
+        let scheduler = TestScheduler::new();
+        let stream = timer(
+            Duration::from_millis(3),
+            Some(Duration::from_millis(1)),
+            &scheduler,
+        )
+        .take(3)
+        .map(to_char);
+
+        assert_marbles(&scheduler, stream, "---01(2|)");
+    }
+
+    #[test]
+    fn as_sampler() {
+        let scheduler = TestScheduler::new();
+        let stream =
+            cold("abcdefgh|", &scheduler).sample(interval(Duration::from_millis(3), &scheduler));
+
+        assert_marbles(&scheduler, stream, "---d--g--(h|)");
+    }
+}
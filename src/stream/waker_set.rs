@@ -0,0 +1,50 @@
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Waker},
+};
+
+use futures::task::{waker_ref, ArcWake};
+
+/// Collects the wakers of everyone polling a shared upstream `Stream`,
+/// so that all of them are woken when it becomes ready,
+/// instead of only the one which happened to poll it last.
+pub(crate) struct WakerSet {
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl WakerSet {
+    pub(crate) fn new() -> Arc<Self> {
+        Arc::new(Self {
+            wakers: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Registers the waker of `cx`, and then invokes `f` with a `Context` which wakes all registered wakers.
+    pub(crate) fn poll_with<T>(
+        self: &Arc<Self>,
+        cx: &mut Context<'_>,
+        f: impl FnOnce(&mut Context<'_>) -> T,
+    ) -> T {
+        {
+            let mut wakers = self.wakers.lock().unwrap();
+
+            if !wakers.iter().any(|it| it.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+        }
+
+        let waker = waker_ref(self);
+
+        f(&mut Context::from_waker(&waker))
+    }
+}
+
+impl ArcWake for WakerSet {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        let wakers = std::mem::take(&mut *arc_self.wakers.lock().unwrap());
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
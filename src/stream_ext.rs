@@ -1,30 +1,59 @@
-use std::{collections::VecDeque, future::Future, hash::Hash, vec::IntoIter};
+use std::{
+    collections::VecDeque,
+    future::Future,
+    hash::Hash,
+    time::{Duration, Instant},
+    vec::IntoIter,
+};
 
+use accumulate::Accumulate;
+use accumulate_async::AccumulateAsync;
 use buffer::Buffer;
+use catch_error::CatchError;
+use concat_map::ConcatMap;
 use debounce::Debounce;
 use delay_every::DelayEvery;
 use dematerialize::Dematerialize;
 use distinct::Distinct;
 use distinct_until_changed::DistinctUntilChanged;
-use futures::{stream::Iter, Stream};
+use exhaust_map::ExhaustMap;
+use futures::{
+    future::{Map, Pending},
+    stream::Iter,
+    Stream,
+};
+use group_by::GroupBy;
 use inspect_done::InspectDone;
 use materialize::Materialize;
+use merge_map::MergeMap;
 use pairwise::Pairwise;
+use partition::Partition;
 use race::Race;
 use sample::Sample;
 use share::Shared;
+use share_sync::SharedSync;
 use start_with::StartWith;
 use switch_map::SwitchMap;
+use timeout::{Timeout, TimeoutError};
+use timeout_with::TimeoutWith;
 use timing::{Timed, Timing};
+use try_dematerialize::TryDematerialize;
+use try_materialize::TryMaterialize;
 use window::Window;
 
 use crate::{
-    BehaviorSubject, CombineLatest2, Event, EventLite, Notification, PublishSubject, ReplaySubject,
+    scheduler::{Deadline, Scheduler},
+    BehaviorSubject, Event, EventLite, Notification, PublishSubject, ReplaySubject,
+    WithLatestFrom2,
 };
 
 use self::{delay::Delay, end_with::EndWith, throttle::Throttle};
 
+pub mod accumulate;
+pub mod accumulate_async;
 pub mod buffer;
+pub mod catch_error;
+pub mod concat_map;
 pub mod debounce;
 pub mod delay;
 pub mod delay_every;
@@ -32,16 +61,25 @@ pub mod dematerialize;
 pub mod distinct;
 pub mod distinct_until_changed;
 pub mod end_with;
+pub mod exhaust_map;
+pub mod group_by;
 pub mod inspect_done;
 pub mod materialize;
+pub mod merge_map;
 pub mod pairwise;
+pub mod partition;
 pub mod race;
 pub mod sample;
 pub mod share;
+pub mod share_sync;
 pub mod start_with;
 pub mod switch_map;
 pub mod throttle;
+pub mod timeout;
+pub mod timeout_with;
 pub mod timing;
+pub mod try_dematerialize;
+pub mod try_materialize;
 pub mod window;
 
 impl<T: ?Sized> RxExt for T where T: Stream {}
@@ -253,6 +291,195 @@ pub trait RxExt: Stream {
         assert_stream::<Event<Self::Item>, _>(Shared::new(self, ReplaySubject::new()))
     }
 
+    /// Transforms a `Stream` into a broadcast one, which can be subscribed to more than once, after cloning the shared version.
+    ///
+    /// Behavior is exactly like a `ReplaySubject` created using `ReplaySubject::window`, where every new subscription will first
+    /// receive all previously emitted events from the parent `Stream` which are not older than `duration`, optionally limited to
+    /// the last `buffer_size` events.
+    /// Every new subscription will produce a unique `Stream` which only emits `Event` objects.
+    /// An `Event` is a helper object which wraps a ref counted value.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use std::time::Duration;
+    ///
+    /// use futures::stream::{StreamExt, self};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter(0..=3);
+    /// let stream = stream.share_replay_window(Duration::from_secs(5), Some(2));
+    ///
+    /// stream.clone().collect::<Vec<_>>().await; // consume all events beforehand
+    ///
+    /// assert_eq!(vec![2, 3], stream.clone().map(|event| *event).collect::<Vec<_>>().await);
+    /// # });
+    ///
+    /// #
+    /// ```
+    fn share_replay_window(
+        self,
+        duration: Duration,
+        buffer_size: Option<usize>,
+    ) -> Shared<Self, ReplaySubject<Self::Item>>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Event<Self::Item>, _>(Shared::new(
+            self,
+            ReplaySubject::window(duration, buffer_size),
+        ))
+    }
+
+    /// Like `share_replay_window`, but measures time using `scheduler` instead of the wall clock.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use futures::{executor::block_on, stream::{StreamExt, self}};
+    /// use futures_rx::{RxExt, TestScheduler};
+    ///
+    /// let scheduler = TestScheduler::new();
+    /// let stream = stream::iter(0..=3);
+    /// let stream = stream.share_replay_window_with_scheduler(Duration::from_secs(5), None, &scheduler);
+    ///
+    /// block_on(stream.clone().collect::<Vec<_>>()); // consume all events beforehand
+    ///
+    /// scheduler.advance_by(Duration::from_secs(6));
+    ///
+    /// assert!(block_on(stream.clone().collect::<Vec<_>>()).is_empty());
+    /// ```
+    fn share_replay_window_with_scheduler<Sch: Scheduler>(
+        self,
+        duration: Duration,
+        buffer_size: Option<usize>,
+        scheduler: &Sch,
+    ) -> Shared<Self, ReplaySubject<Self::Item, Sch>>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Event<Self::Item>, _>(Shared::new(
+            self,
+            ReplaySubject::window_with_scheduler(duration, buffer_size, scheduler),
+        ))
+    }
+
+    /// Like `share`, but the shared `Stream` is `Send` and `Sync`, so that clones
+    /// can be moved into other threads or tasks of a multithreaded runtime.
+    ///
+    /// Behavior is exactly like a `PublishSubject`, every new subscription will produce a unique `Stream` which only emits `Event` objects.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use std::thread;
+    ///
+    /// use futures::{executor::block_on, stream::{StreamExt, self}};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter(0..=3);
+    /// let stream = stream.share_sync();
+    /// let sub_stream = stream.clone();
+    /// let handle = thread::spawn(move || block_on(sub_stream.map(|event| *event).collect::<Vec<_>>()));
+    ///
+    /// assert_eq!(vec![0, 1, 2, 3], stream.map(|event| *event).collect::<Vec<_>>().await);
+    /// assert_eq!(vec![0, 1, 2, 3], handle.join().unwrap());
+    /// # });
+    ///
+    /// #
+    /// ```
+    fn share_sync(self) -> SharedSync<Self, PublishSubject<Self::Item>>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Event<Self::Item>, _>(SharedSync::new(self, PublishSubject::new()))
+    }
+
+    /// Like `share_behavior`, but the shared `Stream` is `Send` and `Sync`, so that clones
+    /// can be moved into other threads or tasks of a multithreaded runtime.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use std::thread;
+    ///
+    /// use futures::{executor::block_on, stream::{StreamExt, self}};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter(1..=3);
+    /// let stream = stream.share_behavior_sync(0);
+    ///
+    /// stream.clone().collect::<Vec<_>>().await; // consume all events beforehand
+    ///
+    /// let sub_stream = stream.clone();
+    /// let handle = thread::spawn(move || block_on(sub_stream.map(|event| *event).collect::<Vec<_>>()));
+    ///
+    /// assert_eq!(vec![3], handle.join().unwrap());
+    /// # });
+    /// ```
+    fn share_behavior_sync(
+        self,
+        initial_value: Self::Item,
+    ) -> SharedSync<Self, BehaviorSubject<Self::Item>>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Event<Self::Item>, _>(SharedSync::new(
+            self,
+            BehaviorSubject::new(initial_value),
+        ))
+    }
+
+    /// Like `share_replay`, but the shared `Stream` is `Send` and `Sync`, so that clones
+    /// can be moved into other threads or tasks of a multithreaded runtime.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use std::thread;
+    ///
+    /// use futures::{executor::block_on, stream::{StreamExt, self}};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter(0..=3);
+    /// let stream = stream.share_replay_sync();
+    ///
+    /// stream.clone().collect::<Vec<_>>().await; // consume all events beforehand
+    ///
+    /// let sub_stream = stream.clone();
+    /// let handle = thread::spawn(move || block_on(sub_stream.map(|event| *event).collect::<Vec<_>>()));
+    ///
+    /// assert_eq!(vec![0, 1, 2, 3], handle.join().unwrap());
+    /// # });
+    /// ```
+    fn share_replay_sync(self) -> SharedSync<Self, ReplaySubject<Self::Item>>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Event<Self::Item>, _>(SharedSync::new(self, ReplaySubject::new()))
+    }
+
     /// Like `flat_map`, except that switched `Stream` is interrupted when the parent `Stream` emits a next event.
     ///
     /// Note that this function consumes the stream passed into it and returns a
@@ -280,6 +507,170 @@ pub trait RxExt: Stream {
         assert_stream::<<F::Output as Stream>::Item, _>(SwitchMap::new(self, f))
     }
 
+    /// Like `flat_map`, except that events from the parent `Stream` are buffered
+    /// while the current inner `Stream` is not done.
+    /// Inner `Stream`s are then created and polled one at a time, in the order in which
+    /// the parent `Stream` emitted the events.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter(0..=3);
+    /// let stream = stream.concat_map(|event| stream::iter([event + 10, event - 10]));
+    ///
+    /// assert_eq!(vec![10, -10, 11, -9, 12, -8, 13, -7], stream.collect::<Vec<_>>().await);
+    /// # });
+    ///
+    /// #
+    /// ```
+    fn concat_map<S: Stream, F: FnMut(Self::Item) -> S>(self, f: F) -> ConcatMap<Self, S, F>
+    where
+        Self: Sized,
+    {
+        assert_stream::<<F::Output as Stream>::Item, _>(ConcatMap::new(self, f))
+    }
+
+    /// Like `flat_map`, except that events from the parent `Stream` are ignored
+    /// for as long as the current inner `Stream` is not done.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_rx::{marble::{assert_marbles, cold}, RxExt, TestScheduler};
+    ///
+    /// let scheduler = TestScheduler::new();
+    /// let stream = cold("ab---c--|", &scheduler).exhaust_map({
+    ///     let scheduler = scheduler.clone();
+    ///
+    ///     move |_| cold("x-y|", &scheduler)
+    /// });
+    ///
+    /// // `b` arrives while the first inner `Stream` is still active, and is ignored
+    /// assert_marbles(&scheduler, stream, "x-y--x-y|");
+    /// ```
+    fn exhaust_map<S: Stream, F: FnMut(Self::Item) -> S>(self, f: F) -> ExhaustMap<Self, S, F>
+    where
+        Self: Sized,
+    {
+        assert_stream::<<F::Output as Stream>::Item, _>(ExhaustMap::new(self, f))
+    }
+
+    /// Like `flat_map`, except that up to `limit` inner `Stream`s are polled concurrently,
+    /// and their events are interleaved as they are emitted.
+    ///
+    /// While `limit` inner `Stream`s are active, the parent `Stream` is not polled,
+    /// until one of the inner `Stream`s is done.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter(0..=3);
+    /// let stream = stream.merge_map(2, |event| stream::iter([event + 10, event - 10]));
+    /// let mut all_events = stream.collect::<Vec<_>>().await;
+    ///
+    /// all_events.sort();
+    ///
+    /// assert_eq!(vec![-10, -9, -8, -7, 10, 11, 12, 13], all_events);
+    /// # });
+    ///
+    /// #
+    /// ```
+    fn merge_map<S: Stream, F: FnMut(Self::Item) -> S>(
+        self,
+        limit: usize,
+        f: F,
+    ) -> MergeMap<Self, S, F>
+    where
+        Self: Sized,
+    {
+        assert_stream::<<F::Output as Stream>::Item, _>(MergeMap::new(self, limit, f))
+    }
+
+    /// Splits the `Stream` into keyed sub-streams, using `key_fn` to determine the key of every event.
+    /// Emits a tuple of the key and a `GroupedStream` whenever an event with a new key is emitted,
+    /// that event and all next events with the same key are then emitted by that `GroupedStream`.
+    ///
+    /// All groups complete when the parent `Stream` is done.
+    /// The parent `Stream` is polled by both the `GroupBy` `Stream` and all `GroupedStream`s,
+    /// so that each group can be consumed independently.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter(0..6);
+    /// let stream = stream
+    ///     .group_by(|event| event % 2 == 0)
+    ///     .flat_map(|(is_even, group)| group.map(move |event| (is_even, event)));
+    ///
+    /// assert_eq!(
+    ///     vec![(true, 0), (true, 2), (true, 4), (false, 1), (false, 3), (false, 5)],
+    ///     stream.collect::<Vec<_>>().await
+    /// );
+    /// # });
+    ///
+    /// #
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn group_by<K: Hash + Eq + Clone, F: FnMut(&Self::Item) -> K>(
+        self,
+        key_fn: F,
+    ) -> GroupBy<Self, K, F, Pending<()>, fn(&K) -> Pending<()>>
+    where
+        Self: Sized,
+    {
+        GroupBy::new(self, key_fn, None)
+    }
+
+    /// Like `group_by`, except that every group also completes when the `Future`
+    /// returned from `duration_fn` completes.
+    /// A next event with the same key will then open a new group.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    fn group_by_until<
+        K: Hash + Eq + Clone,
+        F: FnMut(&Self::Item) -> K,
+        Fut: Future,
+        D: FnMut(&K) -> Fut,
+    >(
+        self,
+        key_fn: F,
+        duration_fn: D,
+    ) -> GroupBy<Self, K, F, Fut, D>
+    where
+        Self: Sized,
+    {
+        GroupBy::new(self, key_fn, Some(duration_fn))
+    }
+
     /// Emits pairs of the previous and next events as a tuple.
     ///
     /// Note that this function consumes the stream passed into it and returns a
@@ -313,6 +704,116 @@ pub trait RxExt: Stream {
         assert_stream::<(Self::Item, EventLite<Self::Item>), _>(Pairwise::new(self))
     }
 
+    /// Applies an accumulator function over the `Stream`, starting with `seed`,
+    /// and emits every intermediate accumulated value.
+    /// The accumulator is passed by value, as with `accumulate_async`.
+    ///
+    /// This is the Rx `scan` operator, it is named `accumulate` to avoid
+    /// clashing with `StreamExt::scan`.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter(1..=4);
+    /// let stream = stream.accumulate(0, |acc, event| acc + event);
+    ///
+    /// assert_eq!(vec![1, 3, 6, 10], stream.collect::<Vec<_>>().await);
+    /// # });
+    ///
+    /// #
+    /// ```
+    fn accumulate<Acc: Clone, F: FnMut(Acc, Self::Item) -> Acc>(
+        self,
+        seed: Acc,
+        f: F,
+    ) -> Accumulate<Self, Acc, F>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Acc, _>(Accumulate::new(self, seed, f))
+    }
+
+    /// Like `accumulate`, except that the accumulator function returns a `Future`.
+    /// The parent `Stream` is not polled while this `Future` is not yet complete.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter(1..=4);
+    /// let stream = stream.accumulate_async(0, |acc, event| async move { acc + event });
+    ///
+    /// assert_eq!(vec![1, 3, 6, 10], stream.collect::<Vec<_>>().await);
+    /// # });
+    ///
+    /// #
+    /// ```
+    fn accumulate_async<Acc: Clone, Fut: Future<Output = Acc>, F: FnMut(Acc, Self::Item) -> Fut>(
+        self,
+        seed: Acc,
+        f: F,
+    ) -> AccumulateAsync<Self, Acc, Fut, F>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Acc, _>(AccumulateAsync::new(self, seed, f))
+    }
+
+    /// Splits the `Stream` into two `Stream`s, the first one emitting all events which match `predicate`,
+    /// and the second one emitting all events which do not.
+    ///
+    /// Both `Stream`s share a single subscription to the parent `Stream`, which is polled only once,
+    /// regardless of which side is polled. Every event is tested against `predicate` once,
+    /// and is only buffered by the side it belongs to. Like `share`, both `Stream`s emit `Event` objects.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::{stream::{StreamExt, self}, future::join};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter(0..=5);
+    /// let (even, odd) = stream.partition(|event| event % 2 == 0);
+    ///
+    /// assert_eq!(
+    ///     (vec![0, 2, 4], vec![1, 3, 5]),
+    ///     join(
+    ///         even.map(|event| *event).collect::<Vec<_>>(),
+    ///         odd.map(|event| *event).collect::<Vec<_>>()
+    ///     )
+    ///     .await
+    /// );
+    /// # });
+    ///
+    /// #
+    /// ```
+    fn partition<F: Fn(&Self::Item) -> bool>(
+        self,
+        predicate: F,
+    ) -> (Partition<Self, F>, Partition<Self, F>)
+    where
+        Self: Sized,
+    {
+        Partition::new(self, predicate)
+    }
+
     /// Delays events using a debounce time window.
     /// The event will emit when this window closes and when no other event
     /// was emitted while this window was open.
@@ -334,6 +835,21 @@ pub trait RxExt: Stream {
         assert_stream::<Self::Item, _>(Debounce::new(self, f))
     }
 
+    /// Like `debounce`, but using a fixed time window of `duration`,
+    /// which is scheduled on `scheduler`.
+    fn debounce_time<Sch: Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: &Sch,
+    ) -> Debounce<Self, Sch::Sleep, impl FnMut(&Self::Item) -> Sch::Sleep + use<Self, Sch>>
+    where
+        Self: Sized,
+    {
+        let scheduler = scheduler.clone();
+
+        self.debounce(move |_| scheduler.sleep(duration))
+    }
+
     /// Creates a new interval from the closure, whenever a new event is emitted from the parent `Stream`.
     /// This event is immediately emitted, however for as long as the interval is now open, no
     /// subsequent events will be emitted.
@@ -375,6 +891,21 @@ pub trait RxExt: Stream {
         assert_stream::<Self::Item, _>(Throttle::new(self, f, throttle::ThrottleConfig::All))
     }
 
+    /// Like `throttle`, but using a fixed time window of `duration`,
+    /// which is scheduled on `scheduler`.
+    fn throttle_time<Sch: Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: &Sch,
+    ) -> Throttle<Self, Sch::Sleep, impl FnMut(&Self::Item) -> Sch::Sleep + use<Self, Sch>>
+    where
+        Self: Sized,
+    {
+        let scheduler = scheduler.clone();
+
+        self.throttle(move |_| scheduler.sleep(duration))
+    }
+
     /// Creates chunks of buffered data.
     ///
     /// The provided closure is executed over all elements of this stream as
@@ -421,6 +952,26 @@ pub trait RxExt: Stream {
         assert_stream::<VecDeque<Self::Item>, _>(Buffer::new(self, f))
     }
 
+    /// Like `buffer`, but creating a chunk for every time window of `duration`,
+    /// which is scheduled on `scheduler` and opens when the first event of a chunk arrives.
+    #[allow(clippy::type_complexity)]
+    fn buffer_time<Sch: Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: &Sch,
+    ) -> Buffer<
+        Self,
+        Map<Sch::Sleep, fn(()) -> bool>,
+        impl FnMut(&Self::Item, usize) -> Map<Sch::Sleep, fn(()) -> bool> + use<Self, Sch>,
+    >
+    where
+        Self: Sized,
+    {
+        let mut deadline = Deadline::new(duration, scheduler);
+
+        self.buffer(move |_, count| deadline.sleep(count))
+    }
+
     /// Creates chunks of buffered data as new `Stream`s.
     ///
     /// The provided closure is executed over all elements of this stream as
@@ -458,6 +1009,26 @@ pub trait RxExt: Stream {
         assert_stream::<Iter<IntoIter<Self::Item>>, _>(Window::new(self, f))
     }
 
+    /// Like `window`, but creating a chunk for every time window of `duration`,
+    /// which is scheduled on `scheduler` and opens when the first event of a chunk arrives.
+    #[allow(clippy::type_complexity)]
+    fn window_time<Sch: Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: &Sch,
+    ) -> Window<
+        Self,
+        Map<Sch::Sleep, fn(()) -> bool>,
+        impl FnMut(&Self::Item, usize) -> Map<Sch::Sleep, fn(()) -> bool> + use<Self, Sch>,
+    >
+    where
+        Self: Sized,
+    {
+        let mut deadline = Deadline::new(duration, scheduler);
+
+        self.window(move |_, count| deadline.sleep(count))
+    }
+
     /// Ensures that all emitted events are unique.
     /// Events are required to implement `Hash`.
     ///
@@ -582,6 +1153,109 @@ pub trait RxExt: Stream {
         assert_stream::<T, _>(Dematerialize::new(self))
     }
 
+    /// Like `materialize`, but for a `Stream` of `Result` items.
+    /// `Ok` events are converted into `Notification::Next` events, while the first `Err` event
+    /// is converted into a final `Notification::Error` event, after which the `Stream` is done.
+    /// When the `Stream` is done without an error, it will first emit a final `Notification::Complete` event.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_rx::{Notification, RxExt};
+    ///
+    /// let stream = stream::iter([Ok(0), Ok(1), Err("failed"), Ok(2)]);
+    /// let stream = stream.try_materialize();
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         Notification::Next(0),
+    ///         Notification::Next(1),
+    ///         Notification::Error("failed"),
+    ///     ],
+    ///     stream.collect::<Vec<_>>().await
+    /// );
+    /// # });
+    ///
+    /// #
+    /// ```
+    fn try_materialize<T, E>(self) -> TryMaterialize<Self, T, E>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+    {
+        assert_stream::<Notification<T, E>, _>(TryMaterialize::new(self))
+    }
+
+    /// The inverse of try_materialize.
+    /// Use this transformer to translate a `Stream` emitting `Notification` events back
+    /// into a `Stream` emitting `Result` events.
+    /// A `Notification::Error` event is emitted as a final `Err` event.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter([Ok(0), Ok(1), Err("failed"), Ok(2)]);
+    /// let stream = stream.try_materialize().try_dematerialize();
+    ///
+    /// assert_eq!(vec![Ok(0), Ok(1), Err("failed")], stream.collect::<Vec<_>>().await);
+    /// # });
+    ///
+    /// #
+    /// ```
+    fn try_dematerialize<T, E>(self) -> TryDematerialize<Self, T, E>
+    where
+        Self: Stream<Item = Notification<T, E>> + Sized,
+    {
+        assert_stream::<Result<T, E>, _>(TryDematerialize::new(self))
+    }
+
+    /// Use this transformer to recover from an error in a `Stream` of `Result` items.
+    /// When the first `Err` event is encountered, the closure is invoked with that error,
+    /// and the remainder of the `Stream` is replaced with the `Stream` returned from the closure.
+    /// Errors emitted by the fallback `Stream` are passed through as-is.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_rx::RxExt;
+    ///
+    /// let stream = stream::iter([Ok(0), Ok(1), Err("failed"), Ok(2)]);
+    /// let stream = stream.catch_error(|_| stream::iter([Ok(3), Ok(4)]));
+    ///
+    /// assert_eq!(
+    ///     vec![Ok(0), Ok(1), Ok(3), Ok(4)],
+    ///     stream.collect::<Vec<Result<_, &str>>>().await
+    /// );
+    /// # });
+    ///
+    /// #
+    /// ```
+    fn catch_error<T, E, S: Stream<Item = Result<T, E>>, F: FnOnce(E) -> S>(
+        self,
+        f: F,
+    ) -> CatchError<Self, S, F>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+    {
+        assert_stream::<Result<T, E>, _>(CatchError::new(self, f))
+    }
+
     /// Delays emitting events using an initial time window, provided by a closure.
     ///
     /// Note that this function consumes the stream passed into it and returns a
@@ -609,6 +1283,21 @@ pub trait RxExt: Stream {
         assert_stream::<Self::Item, _>(Delay::new(self, f))
     }
 
+    /// Like `delay`, but delaying for a fixed `duration`,
+    /// which is scheduled on `scheduler`.
+    fn delay_time<Sch: Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: &Sch,
+    ) -> Delay<Self, Sch::Sleep, impl FnMut() -> Sch::Sleep + use<Self, Sch>>
+    where
+        Self: Sized,
+    {
+        let scheduler = scheduler.clone();
+
+        self.delay(move || scheduler.sleep(duration))
+    }
+
     /// Delays every event using a time window, provided by a closure.
     ///
     /// Use max_buffer_size to limit the amount of buffered items that are awaiting
@@ -643,8 +1332,27 @@ pub trait RxExt: Stream {
         assert_stream::<Self::Item, _>(DelayEvery::new(self, f, max_buffer_size))
     }
 
-    /// Acts just like a `CombineLatest2`, where every next event is a tuple pair
-    /// containing the last emitted events from both `Stream`s.
+    /// Like `delay_every`, but delaying every event for a fixed `duration`,
+    /// which is scheduled on `scheduler`.
+    fn delay_every_time<Sch: Scheduler>(
+        self,
+        duration: Duration,
+        scheduler: &Sch,
+        max_buffer_size: Option<usize>,
+    ) -> DelayEvery<Self, Sch::Sleep, impl FnMut(&Self::Item) -> Sch::Sleep + use<Self, Sch>>
+    where
+        Self: Sized,
+    {
+        let scheduler = scheduler.clone();
+
+        self.delay_every(move |_| scheduler.sleep(duration), max_buffer_size)
+    }
+
+    /// Emits a tuple pair containing the next event and the last emitted event from the other `Stream`,
+    /// but only whenever this `Stream` emits.
+    /// Events are dropped for as long as the other `Stream` did not yet emit an event.
+    ///
+    /// See also `WithLatestFrom2`..`WithLatestFrom9`, for combining with more `Stream`s.
     ///
     /// Note that this function consumes the stream passed into it and returns a
     /// wrapped version of it.
@@ -659,18 +1367,17 @@ pub trait RxExt: Stream {
     /// let stream = stream::iter(0..=3);
     /// let stream = stream.with_latest_from(stream::iter(0..=3));
     ///
-    /// assert_eq!(vec![(0, 0), (1, 1), (2, 2), (3, 3)], stream.collect::<Vec<_>>().await);
+    /// assert_eq!(vec![(0, 3), (1, 3), (2, 3), (3, 3)], stream.collect::<Vec<_>>().await);
     /// # });
     ///
     /// #
     /// ```
-    fn with_latest_from<S: Stream>(self, stream: S) -> CombineLatest2<Self, S, Self::Item, S::Item>
+    fn with_latest_from<S: Stream>(self, stream: S) -> WithLatestFrom2<Self, S, Self::Item, S::Item>
     where
         Self: Sized,
-        Self::Item: ToOwned<Owned = Self::Item>,
         S::Item: ToOwned<Owned = S::Item>,
     {
-        assert_stream::<(Self::Item, S::Item), _>(CombineLatest2::new(self, stream))
+        assert_stream::<(Self::Item, S::Item), _>(WithLatestFrom2::new(self, stream))
     }
 
     /// Wraps each item into a `Timed` struct.
@@ -684,7 +1391,82 @@ pub trait RxExt: Stream {
     where
         Self: Sized,
     {
-        assert_stream::<Timed<Self::Item>, _>(Timing::new(self))
+        assert_stream::<Timed<Self::Item>, _>(Timing::new(self, Instant::now))
+    }
+
+    /// Like `timing`, but taking timestamps from `scheduler`.
+    fn timing_with<Sch: Scheduler>(
+        self,
+        scheduler: &Sch,
+    ) -> Timing<Self, impl FnMut() -> Instant + use<Self, Sch>>
+    where
+        Self: Sized,
+    {
+        let scheduler = scheduler.clone();
+
+        assert_stream::<Timed<Self::Item>, _>(Timing::new(self, move || scheduler.now()))
+    }
+
+    /// Emits every event as `Ok`, but if no event arrives within `duration`, scheduled on `scheduler`,
+    /// after the `Stream` was first polled or after the previous event,
+    /// then a final `Err(TimeoutError)` is emitted and the `Stream` is done.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use futures_rx::{marble::{assert_try_marbles, cold}, RxExt, TestScheduler};
+    ///
+    /// let scheduler = TestScheduler::new();
+    /// let stream = cold("a----b|", &scheduler).timeout(Duration::from_millis(2), &scheduler);
+    ///
+    /// assert_try_marbles(&scheduler, stream, "a-#");
+    /// ```
+    fn timeout<Sch: Scheduler>(self, duration: Duration, scheduler: &Sch) -> Timeout<Self, Sch>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Result<Self::Item, TimeoutError>, _>(Timeout::new(
+            self, duration, scheduler,
+        ))
+    }
+
+    /// Like `timeout`, but instead of emitting an error,
+    /// switches to the `fallback` `Stream` when no event arrives in time.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use futures_rx::{marble::{assert_marbles, cold}, RxExt, TestScheduler};
+    ///
+    /// let scheduler = TestScheduler::new();
+    /// let stream = cold("a----b|", &scheduler).timeout_with(
+    ///     Duration::from_millis(2),
+    ///     cold("xy|", &scheduler),
+    ///     &scheduler,
+    /// );
+    ///
+    /// assert_marbles(&scheduler, stream, "a-xy|");
+    /// ```
+    fn timeout_with<S: Stream<Item = Self::Item>, Sch: Scheduler>(
+        self,
+        duration: Duration,
+        fallback: S,
+        scheduler: &Sch,
+    ) -> TimeoutWith<Self, S, Sch>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(TimeoutWith::new(self, duration, fallback, scheduler))
     }
 
     /// Similar to `inspect`, except that the closure provided is only ever
@@ -725,6 +1507,9 @@ pub trait RxExt: Stream {
     /// If the `sampler` triggers before the source `Stream` was
     /// able to produce a new event, then no event is emitted.
     ///
+    /// Once the source `Stream` is done, a last pending event is still
+    /// emitted when the `sampler` next triggers, after which this `Stream` is done too.
+    ///
     /// Note that this function consumes the stream passed into it and returns a
     /// wrapped version of it.
     ///
@@ -0,0 +1,107 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`accumulate`](RxStreamExt::accumulate) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Accumulate<S: Stream, Acc, F> {
+        #[pin]
+        stream: Fuse<S>,
+        acc: Option<Acc>,
+        f: F,
+    }
+}
+
+impl<S: Stream, Acc, F> Accumulate<S, Acc, F> {
+    pub(crate) fn new(stream: S, seed: Acc, f: F) -> Self {
+        Self {
+            stream: stream.fuse(),
+            acc: Some(seed),
+            f,
+        }
+    }
+}
+
+impl<S: Stream, Acc, F> FusedStream for Accumulate<S, Acc, F>
+where
+    F: FnMut(Acc, S::Item) -> Acc,
+    Acc: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<S: Stream, Acc, F> Stream for Accumulate<S, Acc, F>
+where
+    F: FnMut(Acc, S::Item) -> Acc,
+    Acc: Clone,
+{
+    type Item = Acc;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(event)) => match this.acc.take() {
+                Some(acc) => {
+                    let acc = (this.f)(acc, event);
+
+                    this.acc.replace(acc.clone());
+
+                    Poll::Ready(Some(acc))
+                }
+                None => Poll::Ready(None),
+            },
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use crate::RxExt;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let all_events = stream::iter(1..=4)
+                .accumulate(0, |acc, it| acc + it)
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [1, 3, 6, 10]);
+        });
+    }
+
+    #[test]
+    fn takes_ownership_of_accumulator() {
+        block_on(async {
+            let all_events = stream::iter(1..=3)
+                .accumulate(Vec::new(), |mut acc, it| {
+                    acc.push(it);
+
+                    acc
+                })
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [vec![1], vec![1, 2], vec![1, 2, 3]]);
+        });
+    }
+}
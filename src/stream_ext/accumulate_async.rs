@@ -0,0 +1,116 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`accumulate_async`](RxStreamExt::accumulate_async) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct AccumulateAsync<S: Stream, Acc, Fut, F> {
+        #[pin]
+        stream: Fuse<S>,
+        acc: Option<Acc>,
+        #[pin]
+        current_accumulation: Option<Fut>,
+        f: F,
+    }
+}
+
+impl<S: Stream, Acc, Fut, F> AccumulateAsync<S, Acc, Fut, F> {
+    pub(crate) fn new(stream: S, seed: Acc, f: F) -> Self {
+        Self {
+            stream: stream.fuse(),
+            acc: Some(seed),
+            current_accumulation: None,
+            f,
+        }
+    }
+}
+
+impl<S: Stream, Acc, Fut, F> FusedStream for AccumulateAsync<S, Acc, Fut, F>
+where
+    F: FnMut(Acc, S::Item) -> Fut,
+    Fut: Future<Output = Acc>,
+    Acc: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.current_accumulation.is_none()
+    }
+}
+
+impl<S: Stream, Acc, Fut, F> Stream for AccumulateAsync<S, Acc, Fut, F>
+where
+    F: FnMut(Acc, S::Item) -> Fut,
+    Fut: Future<Output = Acc>,
+    Acc: Clone,
+{
+    type Item = Acc;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if this.current_accumulation.is_none() {
+            match this.stream.poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    if let Some(acc) = this.acc.take() {
+                        this.current_accumulation.set(Some((this.f)(acc, event)));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        match this.current_accumulation.as_mut().as_pin_mut() {
+            Some(accumulation) => match accumulation.poll(cx) {
+                Poll::Ready(acc) => {
+                    this.current_accumulation.set(None);
+                    this.acc.replace(acc.clone());
+
+                    Poll::Ready(Some(acc))
+                }
+                Poll::Pending => Poll::Pending,
+            },
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.current_accumulation.is_some());
+        let (lower, upper) = self.stream.size_hint();
+
+        (lower + pending, upper.map(|it| it + pending))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use crate::RxExt;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let all_events = stream::iter(1..=4)
+                .accumulate_async(vec![], |mut acc, it| async move {
+                    acc.push(it);
+                    acc
+                })
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(
+                all_events,
+                [vec![1], vec![1, 2], vec![1, 2, 3], vec![1, 2, 3, 4]]
+            );
+        });
+    }
+}
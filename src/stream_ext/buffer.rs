@@ -128,9 +128,14 @@ where
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use futures::{executor::block_on, stream, StreamExt};
 
-    use crate::RxExt;
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, TestScheduler,
+    };
 
     #[test]
     fn smoke() {
@@ -146,4 +151,14 @@ mod test {
             );
         });
     }
+
+    #[test]
+    fn buffer_time() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("a--b--c---d|", &scheduler)
+            .buffer_time(Duration::from_millis(4), &scheduler)
+            .flat_map(stream::iter);
+
+        assert_marbles(&scheduler, stream, "----(ab)--c(d|)");
+    }
 }
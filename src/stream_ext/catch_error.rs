@@ -0,0 +1,167 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`catch_error`](RxStreamExt::catch_error) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct CatchError<S1, S2, F> {
+        #[pin]
+        stream: Option<S1>,
+        #[pin]
+        fallback: Option<S2>,
+        f: Option<F>,
+        is_done: bool,
+    }
+}
+
+impl<S1, S2, F> CatchError<S1, S2, F> {
+    pub(crate) fn new(stream: S1, f: F) -> Self {
+        Self {
+            stream: Some(stream),
+            fallback: None,
+            f: Some(f),
+            is_done: false,
+        }
+    }
+}
+
+impl<S1, S2, F, T, E> FusedStream for CatchError<S1, S2, F>
+where
+    S1: Stream<Item = Result<T, E>>,
+    S2: Stream<Item = Result<T, E>>,
+    F: FnOnce(E) -> S2,
+{
+    fn is_terminated(&self) -> bool {
+        self.is_done
+    }
+}
+
+impl<S1, S2, F, T, E> Stream for CatchError<S1, S2, F>
+where
+    S1: Stream<Item = Result<T, E>>,
+    S2: Stream<Item = Result<T, E>>,
+    F: FnOnce(E) -> S2,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.is_done {
+            return Poll::Ready(None);
+        }
+
+        if let Some(stream) = this.stream.as_mut().as_pin_mut() {
+            match stream.poll_next(cx) {
+                Poll::Ready(Some(Err(error))) => {
+                    let f = this.f.take().expect("fallback is only created once");
+
+                    // the errored source is of no further use once the fallback takes over
+                    this.stream.set(None);
+                    this.fallback.set(Some(f(error)));
+                }
+                Poll::Ready(None) => {
+                    *this.is_done = true;
+
+                    return Poll::Ready(None);
+                }
+                other => return other,
+            }
+        }
+
+        match this.fallback.as_pin_mut() {
+            Some(fallback) => match fallback.poll_next(cx) {
+                Poll::Ready(None) => {
+                    *this.is_done = true;
+
+                    Poll::Ready(None)
+                }
+                other => other,
+            },
+            None => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_done {
+            (0, Some(0))
+        } else if let Some(fallback) = &self.fallback {
+            fallback.size_hint()
+        } else if let Some(stream) = &self.stream {
+            (stream.size_hint().0, None)
+        } else {
+            (0, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{pin::pin, rc::Rc};
+
+    use futures::{
+        executor::block_on,
+        stream,
+        task::{noop_waker, Context},
+        Stream, StreamExt,
+    };
+
+    use crate::RxExt;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let stream = stream::iter([Ok(1), Ok(2), Err("failed"), Ok(3)]);
+            let all_events = stream
+                .catch_error(|_| stream::iter([Ok(4), Err("failed again"), Ok(5)]))
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(
+                all_events,
+                [Ok(1), Ok(2), Ok(4), Err("failed again"), Ok(5)]
+            );
+        });
+    }
+
+    #[test]
+    fn passes_error_to_closure() {
+        block_on(async {
+            let stream = stream::iter([Ok(1), Err(2)]);
+            let all_events = stream
+                .catch_error(|e| stream::iter([Ok(e * 10)]))
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [Ok::<_, i32>(1), Ok(20)]);
+        });
+    }
+
+    #[test]
+    fn drops_stream_once_caught() {
+        let source = Rc::new(());
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut stream = pin!(stream::iter([Err::<i32, _>("failed")])
+            .chain(stream::pending())
+            .map({
+                let source = Rc::clone(&source);
+
+                move |it| {
+                    let _ = &source;
+
+                    it
+                }
+            })
+            .catch_error(|_| stream::pending()));
+
+        assert_eq!(Rc::strong_count(&source), 2);
+        assert!(stream.as_mut().poll_next(&mut cx).is_pending());
+        assert_eq!(Rc::strong_count(&source), 1);
+    }
+}
@@ -0,0 +1,169 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`concat_map`](RxStreamExt::concat_map) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ConcatMap<S: Stream, St: Stream, F: FnMut(S::Item) -> St> {
+        #[pin]
+        stream: Fuse<S>,
+        #[pin]
+        concat_stream: Option<Fuse<F::Output>>,
+        pending_events: VecDeque<S::Item>,
+        f: F,
+    }
+}
+
+impl<S: Stream, St: Stream, F: FnMut(S::Item) -> St> ConcatMap<S, St, F> {
+    pub(crate) fn new(stream: S, f: F) -> Self {
+        Self {
+            stream: stream.fuse(),
+            concat_stream: None,
+            pending_events: VecDeque::new(),
+            f,
+        }
+    }
+}
+
+impl<S: Stream, St: Stream, F: FnMut(S::Item) -> St> FusedStream for ConcatMap<S, St, F> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+            && self.pending_events.is_empty()
+            && self
+                .concat_stream
+                .as_ref()
+                .map(|it| it.is_terminated())
+                .unwrap_or(true)
+    }
+}
+
+impl<S: Stream, St: Stream, F: FnMut(S::Item) -> St> Stream for ConcatMap<S, St, F> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        // the parent stream is polled at most once, so that it cannot starve the inner streams
+        let is_parent_pending = match this.stream.as_mut().poll_next(cx) {
+            Poll::Ready(Some(event)) => {
+                this.pending_events.push_back(event);
+
+                false
+            }
+            Poll::Ready(None) => false,
+            Poll::Pending => true,
+        };
+
+        loop {
+            if this.concat_stream.is_none() {
+                match this.pending_events.pop_front() {
+                    Some(event) => this.concat_stream.set(Some((this.f)(event).fuse())),
+                    None if this.stream.is_terminated() => return Poll::Ready(None),
+                    None => {
+                        // the parent stream may have more events ready, poll it again
+                        if !is_parent_pending {
+                            cx.waker().wake_by_ref();
+                        }
+
+                        return Poll::Pending;
+                    }
+                }
+            }
+
+            if let Some(concat_stream) = this.concat_stream.as_mut().as_pin_mut() {
+                match concat_stream.poll_next(cx) {
+                    Poll::Ready(Some(event)) => return Poll::Ready(Some(event)),
+                    Poll::Ready(None) => this.concat_stream.set(None),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self
+            .concat_stream
+            .as_ref()
+            .map(|it| it.size_hint())
+            .unwrap_or((0, None));
+
+        if self.is_terminated() {
+            (0, Some(0))
+        } else {
+            (lower, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::task::Poll;
+
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use crate::RxExt;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let stream = stream::iter(0usize..=3usize);
+            let all_events = stream
+                .concat_map(|i| stream::iter([i.pow(2), i.pow(3)]))
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [0, 0, 1, 1, 4, 8, 9, 27]);
+        });
+    }
+
+    #[test]
+    fn keeps_order_of_slow_streams() {
+        block_on(async {
+            let stream = stream::iter(0usize..=2usize);
+            let all_events = stream
+                .concat_map(|i| {
+                    let mut phase = 0usize;
+
+                    stream::poll_fn(move |cx| {
+                        phase += 1;
+
+                        match phase {
+                            1 | 3 => {
+                                cx.waker().wake_by_ref();
+
+                                Poll::Pending
+                            }
+                            2 => Poll::Ready(Some((i, 0))),
+                            4 => Poll::Ready(Some((i, 1))),
+                            _ => Poll::Ready(None),
+                        }
+                    })
+                })
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1)]);
+        });
+    }
+
+    #[test]
+    fn polls_infinite_parent() {
+        block_on(async {
+            let all_events = stream::iter(0u64..)
+                .concat_map(|i| stream::iter([i]))
+                .take(3)
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [0, 1, 2]);
+        });
+    }
+}
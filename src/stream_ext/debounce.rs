@@ -105,42 +105,31 @@ where
 
 #[cfg(test)]
 mod test {
-    use futures::{executor::block_on, stream, Stream, StreamExt};
-    use futures_time::{
-        future::{FutureExt, IntoFuture},
-        time::Duration,
-    };
+    use std::time::Duration;
 
-    use crate::RxExt;
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, Scheduler, TestScheduler,
+    };
 
     #[test]
     fn smoke() {
-        let stream = create_stream();
+        let scheduler = TestScheduler::new();
+        let stream = cold("ab-c----de---f--|", &scheduler).debounce({
+            let scheduler = scheduler.clone();
 
-        block_on(async {
-            let all_events = stream
-                .debounce(|_| Duration::from_millis(150).into_future())
-                .collect::<Vec<_>>()
-                .await;
-
-            assert_eq!(all_events, [2, 6, 9]);
+            move |_| scheduler.sleep(Duration::from_millis(3))
         });
-    }
 
-    fn create_stream() -> impl Stream<Item = usize> {
-        stream::unfold(0, move |count| async move {
-            if count < 10 {
-                let interval = match count {
-                    3 | 7 => Duration::from_millis(150),
-                    _ => Duration::from_millis(50),
-                };
+        assert_marbles(&scheduler, stream, "------c-----e---(f|)");
+    }
 
-                async { true }.delay(interval).await;
+    #[test]
+    fn debounce_time() {
+        let scheduler = TestScheduler::new();
+        let stream =
+            cold("a-b------c|", &scheduler).debounce_time(Duration::from_millis(3), &scheduler);
 
-                Some((count, count + 1))
-            } else {
-                None
-            }
-        })
+        assert_marbles(&scheduler, stream, "-----b----(c|)");
     }
 }
@@ -54,24 +54,17 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
 
-        match this.interval.as_mut().as_pin_mut() {
-            Some(mut interval) => match interval.poll_unpin(cx) {
-                Poll::Ready(_) => {
-                    this.interval.set(None);
-                }
-                Poll::Pending => {
-                    cx.waker().wake_by_ref();
-
-                    return Poll::Pending;
-                }
-            },
-            None => {
-                if !*this.did_delay {
-                    *this.did_delay = true;
-                    this.interval.set(Some((this.f)()))
-                }
+        if !*this.did_delay {
+            *this.did_delay = true;
+            this.interval.set(Some((this.f)()));
+        }
+
+        if let Some(mut interval) = this.interval.as_mut().as_pin_mut() {
+            match interval.poll_unpin(cx) {
+                Poll::Ready(_) => this.interval.set(None),
+                Poll::Pending => return Poll::Pending,
             }
-        };
+        }
 
         this.stream.poll_next(cx)
     }
@@ -88,24 +81,38 @@ where
 
 #[cfg(test)]
 mod test {
-    use std::time::Instant;
-
-    use futures::{executor::block_on, stream, StreamExt};
-    use futures_time::{future::IntoFuture, time::Duration};
+    use std::time::Duration;
 
-    use crate::RxExt;
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, Scheduler, TestScheduler,
+    };
 
     #[test]
     fn smoke() {
-        block_on(async {
-            let now = Instant::now();
-            let all_events = stream::iter(0..=3)
-                .delay(|| Duration::from_millis(100).into_future())
-                .collect::<Vec<_>>()
-                .await;
-
-            assert_eq!(all_events, [0, 1, 2, 3]);
-            assert!(now.elapsed().as_millis() >= 100);
+        let scheduler = TestScheduler::new();
+        let stream = cold("ab-c|", &scheduler).delay({
+            let scheduler = scheduler.clone();
+
+            move || scheduler.sleep(Duration::from_millis(2))
         });
+
+        assert_marbles(&scheduler, stream, "--ab-c|");
+    }
+
+    #[test]
+    fn delay_time() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("ab---c|", &scheduler).delay_time(Duration::from_millis(3), &scheduler);
+
+        assert_marbles(&scheduler, stream, "---ab---c|");
+    }
+
+    #[test]
+    fn delays_first_item() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("a|", &scheduler).delay_time(Duration::from_millis(3), &scheduler);
+
+        assert_marbles(&scheduler, stream, "---a|");
     }
 }
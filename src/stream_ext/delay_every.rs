@@ -11,6 +11,8 @@ use futures::{
 };
 use pin_project_lite::pin_project;
 
+use crate::stream::DRAIN_BUDGET;
+
 pin_project! {
     /// Stream for the [`delay`](RxStreamExt::delay) method.
     #[must_use = "streams do nothing unless polled"]
@@ -50,6 +52,8 @@ where
 {
     fn is_terminated(&self) -> bool {
         self.stream.is_terminated()
+            && self.delayed_events.is_empty()
+            && self.current_interval.is_none()
     }
 }
 
@@ -62,50 +66,46 @@ where
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
-        let mut did_push = false;
-
-        if let Poll::Ready(Some(event)) = this.stream.poll_next(cx) {
-            did_push = true;
 
-            if let Some(max_buffer_size) = this.max_buffer_size {
-                while this.delayed_events.len() >= *max_buffer_size {
-                    this.delayed_events.pop_front();
+        // buffer what upstream has ready, but at most a fixed amount per poll,
+        // so that an upstream which is always ready cannot block this poll
+        for _ in 0..DRAIN_BUDGET {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    if let Some(max_buffer_size) = this.max_buffer_size {
+                        while this.delayed_events.len() >= *max_buffer_size {
+                            this.delayed_events.pop_front();
+                        }
+                    }
+
+                    this.delayed_events.push_back(event);
                 }
+                _ => break,
             }
-
-            this.delayed_events.push_back(event);
-        };
+        }
 
         if this.current_interval.is_none() {
-            if this.delayed_events.is_empty() {
-                return Poll::Ready(None);
-            }
-
-            if let Some(event) = this.delayed_events.pop_front() {
-                this.current_interval.set(Some((this.f)(&event)));
-                *this.delayed_event = Some(event);
+            match this.delayed_events.pop_front() {
+                Some(event) => {
+                    this.current_interval.set(Some((this.f)(&event)));
+                    *this.delayed_event = Some(event);
+                }
+                // only done once upstream is done and all delayed events were emitted
+                None if this.stream.is_terminated() => return Poll::Ready(None),
+                None => return Poll::Pending,
             }
         }
 
-        if let Some(interval) = this.current_interval.as_mut().as_pin_mut() {
-            match interval.poll(cx) {
+        match this.current_interval.as_mut().as_pin_mut() {
+            Some(interval) => match interval.poll(cx) {
                 Poll::Ready(_) => {
                     this.current_interval.set(None);
 
                     Poll::Ready(this.delayed_event.take())
                 }
-                Poll::Pending => {
-                    cx.waker().wake_by_ref();
-
-                    Poll::Pending
-                }
-            }
-        } else {
-            if !did_push {
-                cx.waker().wake_by_ref();
-            }
-
-            Poll::Pending
+                Poll::Pending => Poll::Pending,
+            },
+            None => Poll::Pending,
         }
     }
 
@@ -121,24 +121,50 @@ where
 
 #[cfg(test)]
 mod test {
-    use std::time::Instant;
+    use std::time::Duration;
 
-    use futures::{executor::block_on, stream, StreamExt};
-    use futures_time::{future::IntoFuture, time::Duration};
+    use futures::{stream, StreamExt};
 
-    use crate::RxExt;
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, Scheduler, TestScheduler,
+    };
 
     #[test]
     fn smoke() {
-        block_on(async {
-            let now = Instant::now();
-            let all_events = stream::iter(0..=3)
-                .delay_every(|_| Duration::from_millis(50).into_future(), None)
-                .collect::<Vec<_>>()
-                .await;
-
-            assert_eq!(all_events, [0, 1, 2, 3]);
-            assert!(now.elapsed().as_millis() >= 50 * 4);
-        });
+        let scheduler = TestScheduler::new();
+        let stream = cold("abc|", &scheduler).delay_every(
+            {
+                let scheduler = scheduler.clone();
+
+                move |_| scheduler.sleep(Duration::from_millis(2))
+            },
+            None,
+        );
+
+        assert_marbles(&scheduler, stream, "--a-b-(c|)");
+    }
+
+    #[test]
+    fn delay_every_time() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("a-b-----c|", &scheduler).delay_every_time(
+            Duration::from_millis(3),
+            &scheduler,
+            None,
+        );
+
+        assert_marbles(&scheduler, stream, "---a--b----(c|)");
+    }
+
+    #[test]
+    fn delays_infinite_stream() {
+        let scheduler = TestScheduler::new();
+        let stream = stream::iter(0..)
+            .delay_every_time(Duration::from_millis(3), &scheduler, Some(2))
+            .map(|_| 'x')
+            .take(3);
+
+        assert_marbles(&scheduler, stream, "---x--x--(x|)");
     }
 }
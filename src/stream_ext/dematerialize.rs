@@ -40,6 +40,7 @@ impl<S: Stream<Item = Notification<T>>, T> Stream for Dematerialize<S, T> {
         match this.stream.as_mut().poll_next(cx) {
             Poll::Ready(Some(event)) => match event {
                 Notification::Next(event) => Poll::Ready(Some(event)),
+                Notification::Error(error) => match error {},
                 Notification::Complete => Poll::Ready(None),
             },
             Poll::Ready(None) => Poll::Ready(None),
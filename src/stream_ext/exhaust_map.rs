@@ -0,0 +1,139 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{
+    stream::{Fuse, FusedStream},
+    Stream, StreamExt,
+};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`exhaust_map`](RxStreamExt::exhaust_map) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ExhaustMap<S: Stream, St: Stream, F: FnMut(S::Item) -> St> {
+        #[pin]
+        stream: Fuse<S>,
+        #[pin]
+        exhaust_stream: Option<Fuse<F::Output>>,
+        f: F,
+    }
+}
+
+impl<S: Stream, St: Stream, F: FnMut(S::Item) -> St> ExhaustMap<S, St, F> {
+    pub(crate) fn new(stream: S, f: F) -> Self {
+        Self {
+            stream: stream.fuse(),
+            exhaust_stream: None,
+            f,
+        }
+    }
+}
+
+impl<S: Stream, St: Stream, F: FnMut(S::Item) -> St> FusedStream for ExhaustMap<S, St, F> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+            && self
+                .exhaust_stream
+                .as_ref()
+                .map(|it| it.is_terminated())
+                .unwrap_or(true)
+    }
+}
+
+impl<S: Stream, St: Stream, F: FnMut(S::Item) -> St> Stream for ExhaustMap<S, St, F> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(exhaust_stream) = this.exhaust_stream.as_mut().as_pin_mut() {
+                match exhaust_stream.poll_next(cx) {
+                    Poll::Ready(Some(event)) => return Poll::Ready(Some(event)),
+                    Poll::Ready(None) => this.exhaust_stream.set(None),
+                    Poll::Pending => {}
+                }
+            }
+
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    if this.exhaust_stream.is_none() {
+                        this.exhaust_stream.set(Some((this.f)(event).fuse()));
+                    } else {
+                        // events from the parent stream are dropped for as long as the inner stream is active,
+                        // one at a time, so that the parent stream cannot starve the inner stream
+                        cx.waker().wake_by_ref();
+
+                        return Poll::Pending;
+                    }
+                }
+                Poll::Ready(None) if this.exhaust_stream.is_none() => return Poll::Ready(None),
+                _ => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self
+            .exhaust_stream
+            .as_ref()
+            .map(|it| it.size_hint())
+            .unwrap_or((0, None));
+
+        if self.stream.is_terminated() && self.exhaust_stream.is_none() {
+            (0, Some(0))
+        } else {
+            (lower, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, TestScheduler,
+    };
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let stream = stream::iter(0usize..=3usize);
+            let all_events = stream
+                .exhaust_map(|i| stream::iter([i.pow(2), i.pow(3)]))
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [0, 0, 1, 1, 4, 8, 9, 27]);
+        });
+    }
+
+    #[test]
+    fn drops_events_while_inner_is_active() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("ab---c--|", &scheduler).exhaust_map({
+            let scheduler = scheduler.clone();
+
+            move |_| cold("x-y|", &scheduler)
+        });
+
+        assert_marbles(&scheduler, stream, "x-y--x-y|");
+    }
+
+    #[test]
+    fn polls_infinite_parent() {
+        block_on(async {
+            let all_events = stream::iter(0u64..)
+                .exhaust_map(|i| stream::iter([i]))
+                .take(3)
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [0, 1, 2]);
+        });
+    }
+}
@@ -0,0 +1,425 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    future::Future,
+    hash::Hash,
+    pin::Pin,
+    rc::{Rc, Weak},
+    sync::Arc,
+    task::{Context, Poll, Waker},
+};
+
+use futures::{
+    stream::{Fuse, FusedStream},
+    FutureExt, Stream, StreamExt,
+};
+
+use crate::{
+    stream::{waker_set::WakerSet, DRAIN_BUDGET},
+    Controller,
+};
+
+type Group<T> = Rc<RefCell<Controller<T>>>;
+type SharedGroups<S, K, F, Fut, D> = Rc<RefCell<Groups<S, K, F, Fut, D>>>;
+
+/// Stream for the [`group_by`](RxStreamExt::group_by) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupBy<S: Stream, K, F, Fut, D> {
+    inner: SharedGroups<S, K, F, Fut, D>,
+}
+
+/// A keyed sub-stream emitted by [`GroupBy`].
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupedStream<S: Stream, K, F, Fut, D> {
+    inner: SharedGroups<S, K, F, Fut, D>,
+    group: Group<S::Item>,
+}
+
+struct Groups<S: Stream, K, F, Fut, D> {
+    stream: Pin<Box<Fuse<S>>>,
+    key_fn: F,
+    duration_fn: Option<D>,
+    groups: HashMap<K, Weak<RefCell<Controller<S::Item>>>>,
+    durations: Vec<(K, Pin<Box<Fut>>)>,
+    new_groups: VecDeque<(K, Group<S::Item>)>,
+    waker: Option<Waker>,
+    wakers: Arc<WakerSet>,
+}
+
+impl<S: Stream, K, F, Fut, D> GroupBy<S, K, F, Fut, D> {
+    /// Without a `duration_fn`, groups only complete when the parent `Stream` is done.
+    pub(crate) fn new(stream: S, key_fn: F, duration_fn: Option<D>) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(Groups {
+                stream: Box::pin(stream.fuse()),
+                key_fn,
+                duration_fn,
+                groups: HashMap::new(),
+                durations: Vec::new(),
+                new_groups: VecDeque::new(),
+                waker: None,
+                wakers: WakerSet::new(),
+            })),
+        }
+    }
+}
+
+impl<S: Stream, K, F, Fut, D> Groups<S, K, F, Fut, D>
+where
+    K: Hash + Eq + Clone,
+    F: FnMut(&S::Item) -> K,
+    D: FnMut(&K) -> Fut,
+    Fut: Future,
+{
+    /// Polls the parent `Stream` for one next event, and routes it to its group.
+    /// Returns `Poll::Ready(false)` when the parent `Stream` is done.
+    ///
+    /// The parent `Stream` is shared by the `GroupBy` and all groups,
+    /// so it wakes all of them, as any of them may be dropped in the meantime.
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<bool> {
+        let wakers = Arc::clone(&self.wakers);
+
+        wakers.poll_with(cx, |cx| self.poll_parent(cx))
+    }
+
+    fn poll_parent(&mut self, cx: &mut Context<'_>) -> Poll<bool> {
+        let Self {
+            groups, durations, ..
+        } = self;
+
+        durations.retain_mut(|(key, duration)| {
+            if duration.poll_unpin(cx).is_ready() {
+                if let Some(group) = groups.remove(key).and_then(|it| it.upgrade()) {
+                    group.borrow_mut().close();
+                }
+
+                false
+            } else {
+                true
+            }
+        });
+
+        match self.stream.poll_next_unpin(cx) {
+            Poll::Ready(Some(event)) => {
+                let key = (self.key_fn)(&event);
+
+                match self.groups.get(&key).and_then(|it| it.upgrade()) {
+                    Some(group) => group.borrow_mut().push(event),
+                    // either a new key, or the `GroupedStream` of this key was dropped,
+                    // in which case a new group replaces it
+                    None => {
+                        let mut group = Controller::new(None);
+
+                        group.push(event);
+
+                        let group = Rc::new(RefCell::new(group));
+
+                        if let Some(duration_fn) = &mut self.duration_fn {
+                            self.durations.retain(|(it, _)| *it != key);
+                            self.durations
+                                .push((key.clone(), Box::pin(duration_fn(&key))));
+                        }
+
+                        self.groups.insert(key.clone(), Rc::downgrade(&group));
+                        self.new_groups.push_back((key, group));
+
+                        if let Some(waker) = self.waker.take() {
+                            waker.wake();
+                        }
+                    }
+                }
+
+                Poll::Ready(true)
+            }
+            Poll::Ready(None) => {
+                for (_, group) in self.groups.drain() {
+                    if let Some(group) = group.upgrade() {
+                        group.borrow_mut().close();
+                    }
+                }
+
+                self.durations.clear();
+
+                if let Some(waker) = self.waker.take() {
+                    waker.wake();
+                }
+
+                Poll::Ready(false)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<S: Stream, K, F, Fut, D> FusedStream for GroupBy<S, K, F, Fut, D>
+where
+    K: Hash + Eq + Clone,
+    F: FnMut(&S::Item) -> K,
+    D: FnMut(&K) -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        let inner = self.inner.borrow();
+
+        inner.stream.is_terminated() && inner.new_groups.is_empty()
+    }
+}
+
+impl<S: Stream, K, F, Fut, D> Stream for GroupBy<S, K, F, Fut, D>
+where
+    K: Hash + Eq + Clone,
+    F: FnMut(&S::Item) -> K,
+    D: FnMut(&K) -> Fut,
+    Fut: Future,
+{
+    type Item = (K, GroupedStream<S, K, F, Fut, D>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut inner = self.inner.borrow_mut();
+
+        // events of existing groups are routed at most a fixed amount of times per poll,
+        // so that a parent stream which is always ready cannot block this poll
+        for _ in 0..DRAIN_BUDGET {
+            if let Some((key, group)) = inner.new_groups.pop_front() {
+                return Poll::Ready(Some((
+                    key,
+                    GroupedStream {
+                        inner: Rc::clone(&self.inner),
+                        group,
+                    },
+                )));
+            }
+
+            match inner.poll_next(cx) {
+                Poll::Ready(true) => {}
+                Poll::Ready(false) => return Poll::Ready(None),
+                Poll::Pending => {
+                    inner.waker = Some(cx.waker().clone());
+
+                    return Poll::Pending;
+                }
+            }
+        }
+
+        cx.waker().wake_by_ref();
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let inner = self.inner.borrow();
+        let (_, upper) = inner.stream.size_hint();
+        let len = inner.new_groups.len();
+
+        (len, upper.map(|it| it + len))
+    }
+}
+
+impl<S: Stream, K, F, Fut, D> FusedStream for GroupedStream<S, K, F, Fut, D>
+where
+    K: Hash + Eq + Clone,
+    F: FnMut(&S::Item) -> K,
+    D: FnMut(&K) -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        let group = self.group.borrow();
+
+        group.is_done && group.len() == 0
+    }
+}
+
+impl<S: Stream, K, F, Fut, D> Stream for GroupedStream<S, K, F, Fut, D>
+where
+    K: Hash + Eq + Clone,
+    F: FnMut(&S::Item) -> K,
+    D: FnMut(&K) -> Fut,
+    Fut: Future,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // events of other groups are routed at most a fixed amount of times per poll,
+        // so that a parent stream which is always ready cannot block this poll
+        for _ in 0..DRAIN_BUDGET {
+            if let Poll::Ready(it) = self.group.borrow_mut().pop() {
+                return Poll::Ready(it);
+            }
+
+            if self.inner.borrow_mut().poll_next(cx).is_pending() {
+                let mut group = self.group.borrow_mut();
+
+                // the group might have been closed by its duration while polling
+                return match group.pop() {
+                    Poll::Ready(it) => Poll::Ready(it),
+                    Poll::Pending => {
+                        group.register(cx.waker());
+
+                        Poll::Pending
+                    }
+                };
+            }
+        }
+
+        cx.waker().wake_by_ref();
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let group = self.group.borrow();
+        let len = group.len();
+
+        (len, if group.is_done { Some(len) } else { None })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc};
+
+    use futures::{
+        executor::{block_on, LocalPool},
+        future::ready,
+        stream,
+        task::LocalSpawnExt,
+        FutureExt, StreamExt,
+    };
+
+    use crate::{PublishSubject, RxExt, Subject};
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let stream = stream::iter(0usize..10usize);
+            let groups = stream.group_by(|it| it % 3).collect::<Vec<_>>().await;
+            let mut all_events = Vec::new();
+
+            for (key, group) in groups {
+                all_events.push((key, group.collect::<Vec<_>>().await));
+            }
+
+            assert_eq!(
+                all_events,
+                [
+                    (0, vec![0, 3, 6, 9]),
+                    (1, vec![1, 4, 7]),
+                    (2, vec![2, 5, 8])
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn polls_groups_without_parent() {
+        block_on(async {
+            let stream = stream::iter(["a1", "b1", "a2", "b2", "a3"]);
+            let mut groups = stream.group_by(|it| it.chars().next().unwrap());
+            let (key_a, group_a) = groups.next().await.unwrap();
+
+            drop(groups);
+
+            assert_eq!(key_a, 'a');
+            assert_eq!(group_a.collect::<Vec<_>>().await, ["a1", "a2", "a3"]);
+        });
+    }
+
+    #[test]
+    fn completes_groups_by_duration() {
+        block_on(async {
+            let stream = stream::iter([1, 1, 1]);
+            // durations complete immediately, so every next event opens a new group
+            let groups = stream
+                .group_by_until(|it| *it, |_| ready(()))
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(groups.len(), 3);
+
+            for (key, group) in groups {
+                assert_eq!(key, 1);
+                assert_eq!(group.collect::<Vec<_>>().await, [1]);
+            }
+        });
+    }
+
+    #[test]
+    fn keeps_polling_after_a_group_is_dropped() {
+        let subject = PublishSubject::new();
+        let mut groups = subject.subscribe().map(|it| *it).group_by(|it| it % 2);
+        let mut pool = LocalPool::new();
+        let all_events = Rc::new(RefCell::new(Vec::new()));
+
+        subject.next(0);
+        subject.next(1);
+
+        let (_, evens) = pool.run_until(groups.next()).unwrap();
+        let (_, odds) = pool.run_until(groups.next()).unwrap();
+
+        pool.spawner()
+            .spawn_local({
+                let all_events = Rc::clone(&all_events);
+
+                evens.for_each(move |it| {
+                    all_events.borrow_mut().push(it);
+
+                    async {}
+                })
+            })
+            .unwrap();
+        pool.spawner()
+            .spawn_local(odds.take(2).for_each(|_| async {}))
+            .unwrap();
+        pool.run_until_stalled();
+
+        // completes the odd group, which was the last to poll the parent stream
+        subject.next(3);
+        pool.run_until_stalled();
+
+        subject.next(2);
+        subject.next(4);
+        subject.close();
+        pool.run_until_stalled();
+
+        assert_eq!(*all_events.borrow(), [0, 2, 4]);
+    }
+
+    #[test]
+    fn bounds_parent_polls_per_poll() {
+        let stream = stream::iter(0usize..).map(|it| usize::from(it == 0));
+        let mut groups = stream.group_by(|it| *it);
+        let (key, mut group) = groups.next().now_or_never().unwrap().unwrap();
+
+        assert_eq!(key, 1);
+        assert_eq!(group.next().now_or_never(), Some(Some(1)));
+
+        // only events for the other key follow, both are pending, but return control to the executor
+        assert!(group.next().now_or_never().is_none());
+
+        let (key, _zeros) = groups.next().now_or_never().unwrap().unwrap();
+
+        assert_eq!(key, 0);
+        assert!(groups.next().now_or_never().is_none());
+    }
+
+    #[test]
+    fn reopens_dropped_groups() {
+        block_on(async {
+            let stream = stream::iter([1, 2, 1, 2]);
+            let mut groups = stream.group_by(|it| *it);
+            let (_, group) = groups.next().await.unwrap();
+
+            drop(group);
+
+            let keys = groups
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>();
+
+            // the second `1` opens a new group, instead of being dropped
+            assert_eq!(keys, [2, 1]);
+        });
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{
+    stream::{Fuse, FusedStream, SelectAll},
+    Stream, StreamExt,
+};
+use pin_project_lite::pin_project;
+
+use crate::stream::DRAIN_BUDGET;
+
+pin_project! {
+    /// Stream for the [`merge_map`](RxStreamExt::merge_map) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct MergeMap<S: Stream, St: Stream, F: FnMut(S::Item) -> St> {
+        #[pin]
+        stream: Fuse<S>,
+        merge_streams: SelectAll<Pin<Box<F::Output>>>,
+        limit: usize,
+        f: F,
+    }
+}
+
+impl<S: Stream, St: Stream, F: FnMut(S::Item) -> St> MergeMap<S, St, F> {
+    pub(crate) fn new(stream: S, limit: usize, f: F) -> Self {
+        assert!(limit > 0, "merge_map requires a limit of at least 1");
+
+        Self {
+            stream: stream.fuse(),
+            merge_streams: SelectAll::new(),
+            limit,
+            f,
+        }
+    }
+}
+
+impl<S: Stream, St: Stream, F: FnMut(S::Item) -> St> FusedStream for MergeMap<S, St, F> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.merge_streams.is_empty()
+    }
+}
+
+impl<S: Stream, St: Stream, F: FnMut(S::Item) -> St> Stream for MergeMap<S, St, F> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        // the parent stream is pulled at most a fixed amount of times per poll,
+        // so that a parent stream which is always ready cannot block this poll
+        let mut budget = DRAIN_BUDGET;
+
+        loop {
+            while this.merge_streams.len() < *this.limit && !this.stream.is_terminated() {
+                if budget == 0 {
+                    break;
+                }
+
+                budget -= 1;
+
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(event)) => {
+                        this.merge_streams.push(Box::pin((this.f)(event)));
+                    }
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            let len = this.merge_streams.len();
+
+            match this.merge_streams.poll_next_unpin(cx) {
+                Poll::Ready(Some(event)) => return Poll::Ready(Some(event)),
+                Poll::Ready(None) if this.stream.is_terminated() => return Poll::Ready(None),
+                Poll::Ready(None) | Poll::Pending => {
+                    // when an inner stream is done, the parent stream can be polled again
+                    if this.merge_streams.len() == len || this.stream.is_terminated() {
+                        // the parent stream may still have events ready, which were not pulled yet
+                        if budget == 0 && !this.stream.is_terminated() {
+                            cx.waker().wake_by_ref();
+                        }
+
+                        return Poll::Pending;
+                    }
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.stream.is_terminated() {
+            self.merge_streams.size_hint()
+        } else {
+            (self.merge_streams.size_hint().0, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::task::Poll;
+
+    use futures::{executor::block_on, stream, FutureExt, StreamExt};
+
+    use crate::RxExt;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let stream = stream::iter(0usize..=3usize);
+            let all_events = stream
+                .merge_map(4, |i| stream::iter([i.pow(2), i.pow(3)]))
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events.len(), 8);
+
+            for i in 0usize..=3usize {
+                let squared = all_events.iter().position(|it| *it == i.pow(2));
+                let cubed = all_events.iter().rposition(|it| *it == i.pow(3));
+
+                assert!(squared <= cubed);
+            }
+        });
+    }
+
+    #[test]
+    fn limits_concurrent_streams() {
+        block_on(async {
+            let stream = stream::iter(0usize..=3usize);
+            let all_events = stream
+                .merge_map(1, |i| {
+                    let mut phase = 0usize;
+
+                    stream::poll_fn(move |cx| {
+                        phase += 1;
+
+                        match phase {
+                            1 => {
+                                cx.waker().wake_by_ref();
+
+                                Poll::Pending
+                            }
+                            2 => Poll::Ready(Some(i)),
+                            _ => Poll::Ready(None),
+                        }
+                    })
+                })
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [0, 1, 2, 3]);
+        });
+    }
+
+    #[test]
+    fn interleaves_streams() {
+        block_on(async {
+            let stream = stream::iter(0usize..=1usize);
+            let all_events = stream
+                .merge_map(2, |i| {
+                    let mut phase = 0usize;
+
+                    stream::poll_fn(move |cx| {
+                        phase += 1;
+
+                        match phase {
+                            1 | 3 => {
+                                cx.waker().wake_by_ref();
+
+                                Poll::Pending
+                            }
+                            2 => Poll::Ready(Some((i, 0))),
+                            4 => Poll::Ready(Some((i, 1))),
+                            _ => Poll::Ready(None),
+                        }
+                    })
+                })
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events.len(), 4);
+            assert_eq!(all_events[..2], [(0, 0), (1, 0)]);
+        });
+    }
+
+    #[test]
+    fn bounds_parent_pulls_per_poll() {
+        let mut stream = stream::iter(0u64..).merge_map(usize::MAX, |_| stream::pending::<u64>());
+
+        // always pending, but returns control to the executor
+        assert!(stream.next().now_or_never().is_none());
+    }
+}
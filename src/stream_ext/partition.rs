@@ -0,0 +1,278 @@
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+use futures::stream::Fuse;
+use futures::stream::FusedStream;
+use futures::Stream;
+use futures::StreamExt;
+use std::sync::Arc;
+
+use crate::stream::waker_set::WakerSet;
+use crate::stream::DRAIN_BUDGET;
+use crate::Controller;
+use crate::Event;
+
+use super::share::{Lock, SyncLock};
+
+type Half<T> = Option<Controller<Event<T>>>;
+
+/// Stream for the [`partition`](RxStreamExt::partition) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct Partition<S: Stream, F> {
+    inner: <SyncLock as Lock>::Cell<Halves<S, F>>,
+    is_match: bool,
+}
+
+struct Halves<S: Stream, F> {
+    stream: Pin<Box<Fuse<S>>>,
+    predicate: F,
+    matches: Half<S::Item>,
+    non_matches: Half<S::Item>,
+    wakers: Arc<WakerSet>,
+}
+
+impl<S: Stream, F> Partition<S, F> {
+    pub(crate) fn new(stream: S, predicate: F) -> (Self, Self) {
+        let inner = SyncLock::new(Halves {
+            stream: Box::pin(stream.fuse()),
+            predicate,
+            matches: Some(Controller::new(None)),
+            non_matches: Some(Controller::new(None)),
+            wakers: WakerSet::new(),
+        });
+
+        (
+            Self {
+                inner: inner.clone(),
+                is_match: true,
+            },
+            Self {
+                inner,
+                is_match: false,
+            },
+        )
+    }
+}
+
+impl<S: Stream, F> Halves<S, F> {
+    fn half(&mut self, is_match: bool) -> &mut Half<S::Item> {
+        if is_match {
+            &mut self.matches
+        } else {
+            &mut self.non_matches
+        }
+    }
+}
+
+impl<S: Stream, F> Halves<S, F>
+where
+    F: Fn(&S::Item) -> bool,
+{
+    /// Polls the source `Stream` on behalf of both halves, routing every event once,
+    /// to the half it belongs to, until the half of `is_match` has an event.
+    ///
+    /// The source `Stream` wakes both halves, as either of them may be dropped in the meantime.
+    fn poll_half(&mut self, is_match: bool, cx: &mut Context<'_>) -> Poll<Option<Event<S::Item>>> {
+        let wakers = Arc::clone(&self.wakers);
+
+        // the source stream is polled at most a fixed amount of times per poll,
+        // so that a source stream which is always ready cannot block this poll
+        for _ in 0..DRAIN_BUDGET {
+            let half = self.half(is_match).as_mut().unwrap();
+
+            if let Poll::Ready(it) = half.pop() {
+                return Poll::Ready(it);
+            }
+
+            match wakers.poll_with(cx, |cx| self.stream.poll_next_unpin(cx)) {
+                Poll::Ready(Some(value)) => {
+                    let is_match = (self.predicate)(&value);
+
+                    // events of a half which was dropped are discarded
+                    if let Some(half) = self.half(is_match) {
+                        half.push(Event(Arc::new(value)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    for half in [&mut self.matches, &mut self.non_matches]
+                        .into_iter()
+                        .flatten()
+                    {
+                        half.close();
+                    }
+                }
+                Poll::Pending => {
+                    self.half(is_match).as_mut().unwrap().register(cx.waker());
+
+                    return Poll::Pending;
+                }
+            }
+        }
+
+        cx.waker().wake_by_ref();
+
+        Poll::Pending
+    }
+}
+
+impl<S: Stream, F> Drop for Partition<S, F> {
+    fn drop(&mut self) {
+        SyncLock::with(&self.inner, |it| it.half(self.is_match).take());
+    }
+}
+
+impl<S: Stream, F> Stream for Partition<S, F>
+where
+    F: Fn(&S::Item) -> bool,
+{
+    type Item = Event<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        SyncLock::with(&self.inner, |it| it.poll_half(self.is_match, cx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        SyncLock::with(&self.inner, |it| {
+            let (_, upper) = it.stream.size_hint();
+            let len = it.half(self.is_match).as_ref().map_or(0, |it| it.len());
+
+            (len, upper.map(|it| it + len))
+        })
+    }
+}
+
+impl<S: Stream, F> FusedStream for Partition<S, F>
+where
+    F: Fn(&S::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        SyncLock::with(&self.inner, |it| {
+            it.half(self.is_match)
+                .as_ref()
+                .is_none_or(|it| it.is_done && it.len() == 0)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc};
+
+    use futures::{
+        executor::{block_on, LocalPool},
+        future::{abortable, join},
+        stream,
+        task::LocalSpawnExt,
+        FutureExt, StreamExt,
+    };
+
+    use crate::{PublishSubject, RxExt, Subject};
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let (even, odd) = stream::iter(0usize..10usize).partition(|it| it % 2 == 0);
+            let (a, b) = join(
+                even.map(|it| *it).collect::<Vec<_>>(),
+                odd.map(|it| *it).collect::<Vec<_>>(),
+            )
+            .await;
+
+            assert_eq!(a, [0, 2, 4, 6, 8]);
+            assert_eq!(b, [1, 3, 5, 7, 9]);
+        });
+    }
+
+    #[test]
+    fn polls_source_once() {
+        block_on(async {
+            let mut polled = 0usize;
+            let stream = stream::iter(0usize..4usize).inspect(|_| polled += 1);
+            let (even, odd) = stream.partition(|it| it % 2 == 0);
+            let a = even.map(|it| *it).collect::<Vec<_>>().await;
+            let b = odd.map(|it| *it).collect::<Vec<_>>().await;
+
+            assert_eq!(a, [0, 2]);
+            assert_eq!(b, [1, 3]);
+            assert_eq!(polled, 4);
+        });
+    }
+
+    #[test]
+    fn wakes_remaining_half() {
+        let subject = PublishSubject::new();
+        let (even, odd) = subject
+            .subscribe()
+            .map(|it| *it)
+            .partition(|it: &usize| it % 2 == 0);
+        let mut pool = LocalPool::new();
+        let all_events = Rc::new(RefCell::new(Vec::new()));
+        let (odd, handle) = abortable(odd.for_each(|_| async {}));
+
+        pool.spawner()
+            .spawn_local({
+                let all_events = Rc::clone(&all_events);
+
+                even.for_each(move |it| {
+                    all_events.borrow_mut().push(*it);
+
+                    async {}
+                })
+            })
+            .unwrap();
+        pool.spawner()
+            .spawn_local(async {
+                let _ = odd.await;
+            })
+            .unwrap();
+        pool.run_until_stalled();
+
+        // drops the half which was the last to poll the source stream
+        handle.abort();
+        pool.run_until_stalled();
+
+        subject.next(0);
+        subject.next(2);
+        subject.close();
+        pool.run_until_stalled();
+
+        assert_eq!(*all_events.borrow(), [0, 2]);
+    }
+
+    #[test]
+    fn runs_predicate_once() {
+        block_on(async {
+            let calls = std::sync::atomic::AtomicUsize::new(0);
+            let (even, odd) = stream::iter(0usize..4usize).partition(|it| {
+                calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+                it % 2 == 0
+            });
+            let (a, b) = join(
+                even.map(|it| *it).collect::<Vec<_>>(),
+                odd.map(|it| *it).collect::<Vec<_>>(),
+            )
+            .await;
+
+            assert_eq!(a, [0, 2]);
+            assert_eq!(b, [1, 3]);
+            assert_eq!(calls.into_inner(), 4);
+        });
+    }
+
+    #[test]
+    fn bounds_source_polls_per_poll() {
+        fn assert_send<T: Send>(_: &T) {}
+
+        let (mut matches, mut non_matches) = stream::iter(0usize..).partition(|_| true);
+
+        assert_send(&matches);
+
+        // always pending, but returns control to the executor
+        assert!(non_matches.next().now_or_never().is_none());
+        assert_eq!(
+            matches.next().now_or_never().flatten().map(|it| *it),
+            Some(0)
+        );
+    }
+}
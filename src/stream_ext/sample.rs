@@ -45,14 +45,19 @@ impl<S1: Stream, S2: Stream> Stream for Sample<S1, S2> {
     type Item = S1::Item;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let this = self.project();
+        let mut this = self.project();
 
-        if let Poll::Ready(Some(event)) = this.stream.poll_next(cx) {
+        if let Poll::Ready(Some(event)) = this.stream.as_mut().poll_next(cx) {
             this.latest_event.replace(event);
 
             cx.waker().wake_by_ref();
         }
 
+        // once the source is done, only wait for the sampler when there is still an event to emit
+        if this.stream.is_terminated() && this.latest_event.is_none() {
+            return Poll::Ready(None);
+        }
+
         match this.sampler.poll_next(cx) {
             Poll::Ready(Some(_)) => {
                 if this.latest_event.is_some() {
@@ -78,22 +83,16 @@ impl<S1: Stream, S2: Stream> Stream for Sample<S1, S2> {
 
 #[cfg(test)]
 mod test {
-    use futures::{executor::block_on, StreamExt};
-    use futures_time::time::Duration;
-
-    use crate::RxExt;
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, TestScheduler,
+    };
 
     #[test]
     fn smoke() {
-        block_on(async {
-            let stream = futures_time::stream::interval(Duration::from_millis(20))
-                .take(6)
-                .enumerate()
-                .map(|(index, _)| index);
-            let sampler = futures_time::stream::interval(Duration::from_millis(50)).take(6);
-            let all_events = stream.sample(sampler).collect::<Vec<_>>().await;
-
-            assert_eq!(all_events, [1, 3, 5]);
-        });
+        let scheduler = TestScheduler::new();
+        let stream = cold("ab-c--d-ef|", &scheduler).sample(cold("--x--x---x-x|", &scheduler));
+
+        assert_marbles(&scheduler, stream, "--b--c---f|");
     }
 }
@@ -8,50 +8,106 @@ use futures::StreamExt;
 use pin_project_lite::pin_project;
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::Mutex;
 
 use crate::subject::shareable_subject::ShareableSubject;
 use crate::subject::Subject;
 use crate::Event;
 use crate::Observable;
 
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Decides how clones of a [`Shared`] `Stream` hold on to the source `Stream` they share.
+///
+/// This trait is sealed, it is only implemented by [`LocalLock`] and [`SyncLock`].
+pub trait Lock: sealed::Sealed {
+    #[doc(hidden)]
+    type Cell<T>: Clone;
+
+    #[doc(hidden)]
+    fn new<T>(value: T) -> Self::Cell<T>;
+
+    #[doc(hidden)]
+    fn with<T, R>(cell: &Self::Cell<T>, f: impl FnOnce(&mut T) -> R) -> R;
+}
+
+/// Shares the source `Stream` within a single thread, using `Rc<RefCell<_>>`.
+#[derive(Debug)]
+pub enum LocalLock {}
+
+impl sealed::Sealed for LocalLock {}
+
+impl Lock for LocalLock {
+    type Cell<T> = Rc<RefCell<T>>;
+
+    fn new<T>(value: T) -> Self::Cell<T> {
+        Rc::new(RefCell::new(value))
+    }
+
+    fn with<T, R>(cell: &Self::Cell<T>, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut cell.borrow_mut())
+    }
+}
+
+/// Shares the source `Stream` across threads, using `Arc<Mutex<_>>`.
+#[derive(Debug)]
+pub enum SyncLock {}
+
+impl sealed::Sealed for SyncLock {}
+
+impl Lock for SyncLock {
+    type Cell<T> = Arc<Mutex<T>>;
+
+    fn new<T>(value: T) -> Self::Cell<T> {
+        Arc::new(Mutex::new(value))
+    }
+
+    fn with<T, R>(cell: &Self::Cell<T>, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut cell.lock().unwrap())
+    }
+}
+
 pin_project! {
     /// Stream for the [`share`](RxStreamExt::share) method.
     #[must_use = "streams do nothing unless polled"]
-    pub struct Shared<S: Stream, Sub: Subject<Item = S::Item>> {
-        inner: Rc<RefCell<ShareableSubject<S, Sub>>>,
+    pub struct Shared<S: Stream, Sub: Subject<Item = S::Item>, L: Lock = LocalLock> {
+        inner: L::Cell<ShareableSubject<S, Sub>>,
         #[pin]
         stream: Fuse<Observable<S::Item>>,
     }
 }
 
-impl<S: Stream, Sub: Subject<Item = S::Item>> Shared<S, Sub> {
+impl<S: Stream, Sub: Subject<Item = S::Item>, L: Lock> Shared<S, Sub, L> {
     pub(crate) fn new(stream: S, subject: Sub) -> Self {
-        let mut subject = ShareableSubject::new(stream, subject);
+        let subject = ShareableSubject::new(stream, subject);
         let stream = subject.subscribe().fuse();
 
         Self {
-            inner: Rc::new(RefCell::new(subject)),
+            inner: L::new(subject),
             stream,
         }
     }
 }
 
-impl<S: Stream, Sub: Subject<Item = S::Item>> Clone for Shared<S, Sub> {
+impl<S: Stream, Sub: Subject<Item = S::Item>, L: Lock> Clone for Shared<S, Sub, L> {
     fn clone(&self) -> Self {
-        let stream = self.inner.borrow_mut().subscribe().fuse();
+        let stream = L::with(&self.inner, |it| it.subscribe()).fuse();
 
         Self {
-            inner: Rc::clone(&self.inner),
+            inner: self.inner.clone(),
             stream,
         }
     }
 }
 
-impl<S: Stream, Sub: Subject<Item = S::Item>> Stream for Shared<S, Sub> {
+impl<S: Stream, Sub: Subject<Item = S::Item>, L: Lock> Stream for Shared<S, Sub, L> {
     type Item = Event<S::Item>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.inner.borrow_mut().poll_next(cx);
+        L::with(&self.inner, |it| it.poll_next(cx));
         self.stream.poll_next_unpin(cx)
     }
 
@@ -60,7 +116,7 @@ impl<S: Stream, Sub: Subject<Item = S::Item>> Stream for Shared<S, Sub> {
     }
 }
 
-impl<S: Stream, Sub: Subject<Item = S::Item>> FusedStream for Shared<S, Sub> {
+impl<S: Stream, Sub: Subject<Item = S::Item>, L: Lock> FusedStream for Shared<S, Sub, L> {
     fn is_terminated(&self) -> bool {
         self.stream.is_terminated()
     }
@@ -68,9 +124,17 @@ impl<S: Stream, Sub: Subject<Item = S::Item>> FusedStream for Shared<S, Sub> {
 
 #[cfg(test)]
 mod test {
-    use futures::{executor::block_on, future::join, stream, StreamExt};
+    use std::{cell::RefCell, rc::Rc};
 
-    use crate::RxExt;
+    use futures::{
+        executor::{block_on, LocalPool},
+        future::{abortable, join},
+        stream,
+        task::LocalSpawnExt,
+        StreamExt,
+    };
+
+    use crate::{PublishSubject, RxExt, Subject};
 
     #[test]
     fn smoke() {
@@ -84,4 +148,42 @@ mod test {
             assert_eq!(b, [1.into(), 2.into(), 3.into()]);
         });
     }
+
+    #[test]
+    fn wakes_remaining_subscriptions() {
+        let subject = PublishSubject::new();
+        let s1 = subject.subscribe().map(|it| *it).share();
+        let s2 = s1.clone();
+        let mut pool = LocalPool::new();
+        let all_events = Rc::new(RefCell::new(Vec::new()));
+        let (s2, handle) = abortable(s2.for_each(|_| async {}));
+
+        pool.spawner()
+            .spawn_local({
+                let all_events = Rc::clone(&all_events);
+
+                s1.for_each(move |it| {
+                    all_events.borrow_mut().push(*it);
+
+                    async {}
+                })
+            })
+            .unwrap();
+        pool.spawner()
+            .spawn_local(async {
+                let _ = s2.await;
+            })
+            .unwrap();
+        pool.run_until_stalled();
+
+        // drops the subscription which was the last to poll the source stream
+        handle.abort();
+        pool.run_until_stalled();
+
+        subject.next(1);
+        subject.close();
+        pool.run_until_stalled();
+
+        assert_eq!(*all_events.borrow(), [1]);
+    }
 }
@@ -0,0 +1,113 @@
+use super::share::{Shared, SyncLock};
+
+/// Stream for the [`share_sync`](RxStreamExt::share_sync) method,
+/// a [`Shared`] `Stream` which is `Send` and `Sync`.
+pub type SharedSync<S, Sub> = Shared<S, Sub, SyncLock>;
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc, thread};
+
+    use futures::{
+        executor::{block_on, LocalPool},
+        future::abortable,
+        stream,
+        task::LocalSpawnExt,
+        StreamExt,
+    };
+
+    use crate::{PublishSubject, RxExt, Subject};
+
+    #[test]
+    fn smoke() {
+        let stream = stream::iter(1usize..=3usize);
+        let s1 = stream.share_sync();
+        let s2 = s1.clone();
+        let handle = thread::spawn(move || block_on(s2.map(|it| *it).collect::<Vec<_>>()));
+        let a = block_on(s1.map(|it| *it).collect::<Vec<_>>());
+        let b = handle.join().unwrap();
+
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(b, [1, 2, 3]);
+    }
+
+    #[test]
+    fn replays_across_threads() {
+        let stream = stream::iter(0usize..=3usize).share_replay_sync();
+
+        block_on(stream.clone().collect::<Vec<_>>());
+
+        let handles = (0..4)
+            .map(|_| {
+                let stream = stream.clone();
+
+                thread::spawn(move || block_on(stream.map(|it| *it).collect::<Vec<_>>()))
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), [0, 1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn replays_behavior_across_threads() {
+        let stream = stream::iter(1usize..=3usize).share_behavior_sync(0);
+        let early = stream.clone();
+
+        assert_eq!(
+            block_on(early.map(|it| *it).collect::<Vec<_>>()),
+            [0, 1, 2, 3]
+        );
+
+        let handles = (0..4)
+            .map(|_| {
+                let stream = stream.clone();
+
+                thread::spawn(move || block_on(stream.map(|it| *it).collect::<Vec<_>>()))
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), [3]);
+        }
+    }
+
+    #[test]
+    fn wakes_remaining_subscriptions() {
+        let subject = PublishSubject::new();
+        let s1 = subject.subscribe().map(|it| *it).share_sync();
+        let s2 = s1.clone();
+        let mut pool = LocalPool::new();
+        let all_events = Rc::new(RefCell::new(Vec::new()));
+        let (s2, handle) = abortable(s2.for_each(|_| async {}));
+
+        pool.spawner()
+            .spawn_local({
+                let all_events = Rc::clone(&all_events);
+
+                s1.for_each(move |it| {
+                    all_events.borrow_mut().push(*it);
+
+                    async {}
+                })
+            })
+            .unwrap();
+        pool.spawner()
+            .spawn_local(async {
+                let _ = s2.await;
+            })
+            .unwrap();
+        pool.run_until_stalled();
+
+        // drops the subscription which was the last to poll the source stream
+        handle.abort();
+        pool.run_until_stalled();
+
+        subject.next(1);
+        subject.close();
+        pool.run_until_stalled();
+
+        assert_eq!(*all_events.borrow(), [1]);
+    }
+}
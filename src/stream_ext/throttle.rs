@@ -111,53 +111,45 @@ where
 
 #[cfg(test)]
 mod test {
-    use futures::{executor::block_on, stream, Stream, StreamExt};
-    use futures_time::{future::IntoFuture, time::Duration};
+    use std::time::Duration;
 
-    use crate::RxExt;
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, Scheduler, TestScheduler,
+    };
 
     #[test]
     fn smoke() {
-        block_on(async {
-            let stream = create_stream();
-            let all_events = stream
-                .throttle(|_| Duration::from_millis(175).into_future())
-                .collect::<Vec<_>>()
-                .await;
-
-            assert_eq!(all_events, [0, 4, 8]);
-        });
-
-        block_on(async {
-            let stream = create_stream();
-            let all_events = stream
-                .throttle_trailing(|_| Duration::from_millis(175).into_future())
-                .collect::<Vec<_>>()
-                .await;
-
-            assert_eq!(all_events, [3, 7]);
-        });
-
-        block_on(async {
-            let stream = create_stream();
-            let all_events = stream
-                .throttle_all(|_| Duration::from_millis(175).into_future())
-                .collect::<Vec<_>>()
-                .await;
-
-            assert_eq!(all_events, [0, 3, 4, 7, 8]);
-        });
+        let scheduler = TestScheduler::new();
+        let sleep = {
+            let scheduler = scheduler.clone();
+
+            move |_: &char| scheduler.sleep(Duration::from_millis(3))
+        };
+
+        assert_marbles(
+            &scheduler,
+            cold("abc--d-e-----f|", &scheduler).throttle(sleep.clone()),
+            "a----d-------f|",
+        );
+        assert_marbles(
+            &scheduler,
+            cold("abc--d-e-----f|", &scheduler).throttle_trailing(sleep.clone()),
+            "---c----e-----|",
+        );
+        assert_marbles(
+            &scheduler,
+            cold("abc--d-e-----f|", &scheduler).throttle_all(sleep),
+            "a--c-d--e----f|",
+        );
     }
 
-    fn create_stream() -> impl Stream<Item = usize> {
-        stream::unfold(0, move |count| async move {
-            if count < 10 {
-                Duration::from_millis(50).into_future().await;
+    #[test]
+    fn throttle_time() {
+        let scheduler = TestScheduler::new();
+        let stream =
+            cold("abcde-fg---h|", &scheduler).throttle_time(Duration::from_millis(3), &scheduler);
 
-                Some((count, count + 1))
-            } else {
-                None
-            }
-        })
+        assert_marbles(&scheduler, stream, "a--d--f----h|");
     }
 }
@@ -0,0 +1,103 @@
+use std::{
+    error::Error,
+    fmt,
+    future::{ready, Ready},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::{
+    stream::{once, FusedStream, Map, Once},
+    Stream, StreamExt,
+};
+use pin_project_lite::pin_project;
+
+use crate::Scheduler;
+
+use super::timeout_with::TimeoutWith;
+
+type TimeoutResult<S> = Result<<S as Stream>::Item, TimeoutError>;
+
+pin_project! {
+    /// Stream for the [`timeout`](RxStreamExt::timeout) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Timeout<S: Stream, Sch: Scheduler> {
+        #[pin]
+        inner: TimeoutWith<
+            Map<S, fn(S::Item) -> TimeoutResult<S>>,
+            Once<Ready<TimeoutResult<S>>>,
+            Sch,
+        >,
+    }
+}
+
+/// The error emitted by [`timeout`](RxStreamExt::timeout), when no event arrived in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeoutError;
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no event arrived within the timeout duration")
+    }
+}
+
+impl Error for TimeoutError {}
+
+impl<S: Stream, Sch: Scheduler> Timeout<S, Sch> {
+    pub(crate) fn new(stream: S, duration: Duration, scheduler: &Sch) -> Self {
+        // times out by switching to a fallback which only emits the error
+        Self {
+            inner: TimeoutWith::new(
+                stream.map(Ok as fn(S::Item) -> TimeoutResult<S>),
+                duration,
+                once(ready(Err(TimeoutError))),
+                scheduler,
+            ),
+        }
+    }
+}
+
+impl<S: Stream, Sch: Scheduler> FusedStream for Timeout<S, Sch> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S: Stream, Sch: Scheduler> Stream for Timeout<S, Sch> {
+    type Item = TimeoutResult<S>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        marble::{assert_try_marbles, cold},
+        RxExt, TestScheduler,
+    };
+
+    #[test]
+    fn smoke() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("-a--b---c|", &scheduler).timeout(Duration::from_millis(3), &scheduler);
+
+        assert_try_marbles(&scheduler, stream, "-a--b--#");
+    }
+
+    #[test]
+    fn completes_in_time() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("-a--b|", &scheduler).timeout(Duration::from_millis(3), &scheduler);
+
+        assert_try_marbles(&scheduler, stream, "-a--b|");
+    }
+}
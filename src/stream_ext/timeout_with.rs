@@ -0,0 +1,166 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::{stream::FusedStream, Stream};
+use pin_project_lite::pin_project;
+
+use crate::Scheduler;
+
+pin_project! {
+    /// Stream for the [`timeout_with`](RxStreamExt::timeout_with) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TimeoutWith<S1: Stream, S2: Stream, Sch: Scheduler> {
+        // dropped once it timed out
+        #[pin]
+        stream: Option<S1>,
+        #[pin]
+        fallback: S2,
+        duration: Duration,
+        scheduler: Sch,
+        #[pin]
+        sleep: Option<Sch::Sleep>,
+        is_done: bool,
+    }
+}
+
+impl<S1: Stream, S2: Stream, Sch: Scheduler> TimeoutWith<S1, S2, Sch> {
+    pub(crate) fn new(stream: S1, duration: Duration, fallback: S2, scheduler: &Sch) -> Self {
+        Self {
+            stream: Some(stream),
+            fallback,
+            duration,
+            scheduler: scheduler.clone(),
+            sleep: None,
+            is_done: false,
+        }
+    }
+}
+
+impl<S1: Stream, S2: Stream<Item = S1::Item>, Sch: Scheduler> FusedStream
+    for TimeoutWith<S1, S2, Sch>
+{
+    fn is_terminated(&self) -> bool {
+        self.is_done
+    }
+}
+
+impl<S1: Stream, S2: Stream<Item = S1::Item>, Sch: Scheduler> Stream for TimeoutWith<S1, S2, Sch> {
+    type Item = S1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.is_done {
+            return Poll::Ready(None);
+        }
+
+        if let Some(stream) = this.stream.as_mut().as_pin_mut() {
+            if this.sleep.is_none() {
+                this.sleep.set(Some(this.scheduler.sleep(*this.duration)));
+            }
+
+            match stream.poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    this.sleep.set(Some(this.scheduler.sleep(*this.duration)));
+
+                    return Poll::Ready(Some(event));
+                }
+                Poll::Ready(None) => {
+                    *this.is_done = true;
+
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => match this.sleep.as_mut().as_pin_mut().map(|it| it.poll(cx)) {
+                    Some(Poll::Ready(())) => {
+                        this.stream.set(None);
+                        this.sleep.set(None);
+                    }
+                    _ => return Poll::Pending,
+                },
+            }
+        }
+
+        match this.fallback.poll_next(cx) {
+            Poll::Ready(None) => {
+                *this.is_done = true;
+
+                Poll::Ready(None)
+            }
+            other => other,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_done {
+            (0, Some(0))
+        } else if self.stream.is_none() {
+            self.fallback.size_hint()
+        } else {
+            (0, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{rc::Rc, time::Duration};
+
+    use futures::{stream, StreamExt};
+
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, TestScheduler,
+    };
+
+    #[test]
+    fn smoke() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("-a--b---c|", &scheduler).timeout_with(
+            Duration::from_millis(3),
+            cold("xy|", &scheduler),
+            &scheduler,
+        );
+
+        assert_marbles(&scheduler, stream, "-a--b--xy|");
+    }
+
+    #[test]
+    fn completes_in_time() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("-a--b|", &scheduler).timeout_with(
+            Duration::from_millis(3),
+            cold("xy|", &scheduler),
+            &scheduler,
+        );
+
+        assert_marbles(&scheduler, stream, "-a--b|");
+    }
+
+    #[test]
+    fn drops_stream_once_timed_out() {
+        let scheduler = TestScheduler::new();
+        let source = Rc::new(());
+        let stream = stream::pending::<char>()
+            .map({
+                let source = Rc::clone(&source);
+
+                move |it| {
+                    let _ = &source;
+
+                    it
+                }
+            })
+            .timeout_with(Duration::from_millis(3), cold("x", &scheduler), &scheduler);
+
+        assert_eq!(Rc::strong_count(&source), 2);
+
+        scheduler.spawn(stream.for_each(|_| async {}));
+        scheduler.advance_by(Duration::from_millis(3));
+
+        assert_eq!(Rc::strong_count(&source), 1);
+    }
+}
@@ -13,29 +13,31 @@ use pin_project_lite::pin_project;
 pin_project! {
     /// Stream for the [`timing`](RxStreamExt::timing) method.
     #[must_use = "streams do nothing unless polled"]
-    pub struct Timing<S: Stream> {
+    pub struct Timing<S: Stream, N = fn() -> Instant> {
         #[pin]
         stream: Fuse<S>,
+        now: N,
         last_time: Option<Instant>,
     }
 }
 
-impl<S: Stream> Timing<S> {
-    pub(crate) fn new(stream: S) -> Self {
+impl<S: Stream, N> Timing<S, N> {
+    pub(crate) fn new(stream: S, now: N) -> Self {
         Self {
             stream: stream.fuse(),
+            now,
             last_time: None,
         }
     }
 }
 
-impl<S: Stream> FusedStream for Timing<S> {
+impl<S: Stream, N: FnMut() -> Instant> FusedStream for Timing<S, N> {
     fn is_terminated(&self) -> bool {
         self.stream.is_terminated()
     }
 }
 
-impl<S: Stream> Stream for Timing<S> {
+impl<S: Stream, N: FnMut() -> Instant> Stream for Timing<S, N> {
     type Item = Timed<S::Item>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
@@ -43,7 +45,7 @@ impl<S: Stream> Stream for Timing<S> {
 
         match this.stream.poll_next(cx) {
             Poll::Ready(Some(event)) => {
-                let timestamp = Instant::now();
+                let timestamp = (this.now)();
                 let interval = this.last_time.map(|it| timestamp.duration_since(it));
 
                 *this.last_time = Some(timestamp);
@@ -73,55 +75,45 @@ pub struct Timed<T> {
 
 #[cfg(test)]
 mod test {
-    use std::time::Instant;
+    use futures::{executor::block_on, stream, StreamExt};
 
-    use futures::{executor::block_on, stream, Stream, StreamExt};
-    use futures_time::{future::FutureExt, time::Duration};
-
-    use crate::RxExt;
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, TestScheduler,
+    };
 
     #[test]
     fn smoke() {
         block_on(async {
-            let stream = create_stream();
-            let start = Instant::now();
-            let all_events = stream.timing().collect::<Vec<_>>().await;
-            let timestamps = all_events
-                .iter()
-                .map(|it| it.timestamp)
-                .enumerate()
-                .collect::<Vec<_>>();
-            let intervals = all_events
-                .iter()
-                .map(|it| it.interval)
-                .enumerate()
-                .collect::<Vec<_>>();
-
-            for (index, timestamp) in timestamps {
-                assert!(
-                    timestamp.duration_since(start).as_millis() >= (50 * index).try_into().unwrap()
+            let all_events = stream::iter(0..3).timing().collect::<Vec<_>>().await;
+
+            assert_eq!(
+                all_events.iter().map(|it| it.event).collect::<Vec<_>>(),
+                [0, 1, 2]
+            );
+            assert!(all_events[0].interval.is_none());
+
+            for pair in all_events.windows(2) {
+                assert_eq!(
+                    pair[1].interval,
+                    Some(pair[1].timestamp.duration_since(pair[0].timestamp))
                 );
             }
-
-            for (index, interval) in intervals {
-                if index == 0 {
-                    assert!(interval.is_none());
-                } else {
-                    assert!(interval.expect("interval is None!").as_millis() >= 50);
-                }
-            }
         });
     }
 
-    fn create_stream() -> impl Stream<Item = usize> {
-        stream::unfold(0, move |count| async move {
-            if count < 10 {
-                async { true }.delay(Duration::from_millis(50)).await;
-
-                Some((count, count + 1))
-            } else {
-                None
-            }
-        })
+    #[test]
+    fn timing_with() {
+        let scheduler = TestScheduler::new();
+        // every event is replaced by the amount of frames since the previous event
+        let stream = cold("a-b---c|", &scheduler)
+            .timing_with(&scheduler)
+            .map(|it| {
+                let frames = it.interval.unwrap_or_default().as_millis() as u32;
+
+                char::from_digit(frames, 10).unwrap()
+            });
+
+        assert_marbles(&scheduler, stream, "0-2---4|");
     }
 }
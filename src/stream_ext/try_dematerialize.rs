@@ -0,0 +1,93 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::Fuse, stream::FusedStream, Stream, StreamExt};
+use pin_project_lite::pin_project;
+
+use crate::Notification;
+
+pin_project! {
+    /// Stream for the [`try_dematerialize`](RxStreamExt::try_dematerialize) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryDematerialize<S: Stream<Item = Notification<T, E>>, T, E> {
+        #[pin]
+        stream: Fuse<S>,
+        is_done: bool,
+    }
+}
+
+impl<S: Stream<Item = Notification<T, E>>, T, E> TryDematerialize<S, T, E> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream: stream.fuse(),
+            is_done: false,
+        }
+    }
+}
+
+impl<S: Stream<Item = Notification<T, E>>, T, E> FusedStream for TryDematerialize<S, T, E> {
+    fn is_terminated(&self) -> bool {
+        self.is_done || self.stream.is_terminated()
+    }
+}
+
+impl<S: Stream<Item = Notification<T, E>>, T, E> Stream for TryDematerialize<S, T, E> {
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.is_done {
+            return Poll::Ready(None);
+        }
+
+        match this.stream.as_mut().poll_next(cx) {
+            Poll::Ready(Some(event)) => match event {
+                Notification::Next(event) => Poll::Ready(Some(Ok(event))),
+                Notification::Error(error) => {
+                    *this.is_done = true;
+                    Poll::Ready(Some(Err(error)))
+                }
+                Notification::Complete => {
+                    *this.is_done = true;
+                    Poll::Ready(None)
+                }
+            },
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_done {
+            (0, Some(0))
+        } else {
+            let (_, b) = self.stream.size_hint();
+
+            (0, b)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use crate::RxExt;
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let stream = stream::iter([Ok(1), Ok(2), Err("failed"), Ok(3)]);
+            let all_events = stream
+                .try_materialize()
+                .try_dematerialize()
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(all_events, [Ok(1), Ok(2), Err("failed")]);
+        });
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::FusedStream, Stream};
+use pin_project_lite::pin_project;
+
+use crate::Notification;
+
+pin_project! {
+    /// Stream for the [`try_materialize`](RxStreamExt::try_materialize) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryMaterialize<S: Stream<Item = Result<T, E>>, T, E> {
+        #[pin]
+        stream: S,
+        is_done: bool,
+    }
+}
+
+impl<S: Stream<Item = Result<T, E>>, T, E> TryMaterialize<S, T, E> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream,
+            is_done: false,
+        }
+    }
+}
+
+impl<S: Stream<Item = Result<T, E>>, T, E> FusedStream for TryMaterialize<S, T, E> {
+    fn is_terminated(&self) -> bool {
+        self.is_done
+    }
+}
+
+impl<S: Stream<Item = Result<T, E>>, T, E> Stream for TryMaterialize<S, T, E> {
+    type Item = Notification<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.is_done {
+            return Poll::Ready(None);
+        }
+
+        match this.stream.as_mut().poll_next(cx) {
+            Poll::Ready(Some(Ok(event))) => Poll::Ready(Some(Notification::Next(event))),
+            Poll::Ready(Some(Err(error))) => {
+                *this.is_done = true;
+                Poll::Ready(Some(Notification::Error(error)))
+            }
+            Poll::Ready(None) => {
+                *this.is_done = true;
+                Poll::Ready(Some(Notification::Complete))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_done {
+            (0, Some(0))
+        } else {
+            let (_, b) = self.stream.size_hint();
+
+            (1, b.map(|it| it + 1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use crate::{Notification, RxExt};
+
+    #[test]
+    fn smoke() {
+        block_on(async {
+            let stream = stream::iter([Ok(1), Ok(2), Err("failed"), Ok(3)]);
+            let all_events = stream.try_materialize().collect::<Vec<_>>().await;
+
+            assert_eq!(
+                all_events,
+                [
+                    Notification::Next(1),
+                    Notification::Next(2),
+                    Notification::Error("failed"),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn completes_without_error() {
+        block_on(async {
+            let stream = stream::iter([Ok::<_, ()>(1), Ok(2)]);
+            let all_events = stream.try_materialize().collect::<Vec<_>>().await;
+
+            assert_eq!(
+                all_events,
+                [
+                    Notification::Next(1),
+                    Notification::Next(2),
+                    Notification::Complete
+                ]
+            );
+        });
+    }
+}
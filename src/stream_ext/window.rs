@@ -128,9 +128,14 @@ where
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use futures::{executor::block_on, stream, StreamExt};
 
-    use crate::RxExt;
+    use crate::{
+        marble::{assert_marbles, cold},
+        RxExt, TestScheduler,
+    };
 
     #[test]
     fn smoke() {
@@ -158,4 +163,14 @@ mod test {
             );
         });
     }
+
+    #[test]
+    fn window_time() {
+        let scheduler = TestScheduler::new();
+        let stream = cold("a--b--c---d|", &scheduler)
+            .window_time(Duration::from_millis(4), &scheduler)
+            .flat_map(|it| it);
+
+        assert_marbles(&scheduler, stream, "----(ab)--c(d|)");
+    }
 }
@@ -1,19 +1,206 @@
+pub mod async_subject;
 pub mod behavior_subject;
 pub mod publish_subject;
 pub mod replay_subject;
 pub mod shareable_subject;
 
-use std::sync::{Arc, RwLock};
+use std::{
+    future::Future,
+    sync::{Arc, RwLock, Weak},
+    task::{Context, Poll},
+};
 
-use crate::{Controller, Event, Observable};
+use futures::{future::poll_fn, ready};
+
+use crate::{
+    stream::controller::overflow_event, Controller, Event, Observable, Overflow, OverflowStrategy,
+};
 
 type Subscription<T> = Arc<RwLock<Controller<Event<T>>>>;
 
 pub trait Subject {
     type Item;
 
-    fn subscribe(&mut self) -> Observable<Self::Item>;
-    fn close(&mut self);
-    fn next(&mut self, value: Self::Item);
-    fn for_each_subscription<F: FnMut(&mut Subscription<Self::Item>)>(&mut self, f: F);
+    fn subscribe(&self) -> Observable<Self::Item>;
+    fn close(&self);
+    fn next(&self, value: Self::Item);
+    fn for_each_subscription<F: FnMut(&mut Subscription<Self::Item>)>(&self, f: F);
+
+    /// Emits `Err(error)` to all subscriptions, then closes the `Subject`,
+    /// terminating all subscriptions.
+    fn error<T, E>(&self, error: E)
+    where
+        Self: Subject<Item = Result<T, E>>,
+    {
+        self.next(Err(error));
+        self.close();
+    }
+
+    /// Returns `Poll::Ready` when all subscriptions which use `OverflowStrategy::Backpressure`
+    /// have room for a next event.
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<()> {
+        let mut is_ready = true;
+
+        self.for_each_subscription(|it| {
+            if !it.write().unwrap().poll_ready(cx.waker()) {
+                is_ready = false;
+            }
+        });
+
+        if is_ready {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    /// Like `next`, but first waits until all subscriptions which use
+    /// `OverflowStrategy::Backpressure` have room for the event.
+    ///
+    /// `next` on the other hand never waits, and buffers the event regardless of their capacity.
+    fn next_async(&self, value: Self::Item) -> impl Future<Output = ()>
+    where
+        Self: Sized,
+    {
+        let mut value = Some(value);
+
+        poll_fn(move |cx| {
+            ready!(self.poll_ready(cx));
+
+            if let Some(value) = value.take() {
+                self.next(value);
+            }
+
+            Poll::Ready(())
+        })
+    }
+}
+
+/// The subscriptions of a `Subject`, which are all closed when the `Subject` closes or is dropped.
+pub(crate) struct Subscriptions<T> {
+    subscriptions: Vec<Weak<RwLock<Controller<Event<T>>>>>,
+    pub(crate) is_closed: bool,
+    pub(crate) bounds: Option<(usize, OverflowStrategy)>,
+    overflow_event: Option<fn() -> Event<T>>,
+}
+
+impl<T> Subscriptions<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            subscriptions: Vec::new(),
+            is_closed: false,
+            bounds: None,
+            overflow_event: None,
+        }
+    }
+
+    /// Adds a new subscription, which is already done if the `Subject` is closed.
+    pub(crate) fn subscribe(&mut self) -> Subscription<T> {
+        let mut stream = Controller::new(self.bounds);
+
+        stream.overflow_event = self.overflow_event;
+        stream.is_done = self.is_closed;
+
+        let stream = Arc::new(RwLock::new(stream));
+
+        self.subscriptions.push(Arc::downgrade(&stream));
+
+        stream
+    }
+
+    pub(crate) fn bound(&mut self, capacity: usize, overflow: OverflowStrategy) {
+        assert!(capacity > 0, "capacity must be greater than 0");
+
+        self.bounds = Some((capacity, overflow));
+    }
+
+    /// Pushes `value` to all subscriptions.
+    pub(crate) fn push(&mut self, value: &Arc<T>) {
+        self.for_each_subscription(|it| {
+            it.write().unwrap().push(Event(Arc::clone(value)));
+        });
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.is_closed = true;
+
+        self.for_each_subscription(|it| {
+            it.write().unwrap().close();
+        });
+    }
+
+    /// Invokes `f` for every subscription which was not dropped yet.
+    pub(crate) fn for_each_subscription<F: FnMut(&mut Subscription<T>)>(&mut self, mut f: F) {
+        self.subscriptions.retain(|sub| {
+            sub.upgrade().is_some_and(|mut it| {
+                f(&mut it);
+
+                true
+            })
+        });
+    }
 }
+
+impl<T, E: From<Overflow>> Subscriptions<Result<T, E>> {
+    pub(crate) fn bound_with_error(&mut self, capacity: usize) {
+        self.bound(capacity, OverflowStrategy::Terminate);
+        self.overflow_event = Some(overflow_event as fn() -> Event<Result<T, E>>);
+    }
+}
+
+impl<T> Drop for Subscriptions<T> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Implements `bounded`, `bounded_with_error` and `Clone` for a `Subject`
+/// which keeps its `Subscriptions` in `self.inner.write().unwrap().subscriptions`.
+macro_rules! bounded_subject {
+    ($(#[$meta:meta])* $subject:ident<T $(, $param:ident: $bound:path)*>) => {
+        impl<T $(, $param: $bound)*> $subject<T $(, $param)*> {
+            /// Limits the buffer of every subscription to `capacity` events,
+            /// `overflow` decides what happens when a subscription falls behind.
+            $(#[$meta])*
+            ///
+            /// # Panics
+            ///
+            /// Panics if `capacity` is `0`.
+            pub fn bounded(self, capacity: usize, overflow: $crate::OverflowStrategy) -> Self {
+                self.inner
+                    .write()
+                    .unwrap()
+                    .subscriptions
+                    .bound(capacity, overflow);
+                self
+            }
+        }
+
+        impl<T, E: From<$crate::Overflow> $(, $param: $bound)*> $subject<Result<T, E> $(, $param)*> {
+            /// Like `bounded` using `OverflowStrategy::Terminate`, but a subscription which falls behind
+            /// first receives an `Err` event carrying [`Overflow`](crate::Overflow), so that it can be told apart from a regular close.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `capacity` is `0`.
+            pub fn bounded_with_error(self, capacity: usize) -> Self {
+                self.inner
+                    .write()
+                    .unwrap()
+                    .subscriptions
+                    .bound_with_error(capacity);
+                self
+            }
+        }
+
+        impl<T $(, $param)*> Clone for $subject<T $(, $param)*> {
+            fn clone(&self) -> Self {
+                Self {
+                    inner: std::sync::Arc::clone(&self.inner),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use bounded_subject;
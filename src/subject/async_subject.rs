@@ -0,0 +1,152 @@
+use std::sync::{Arc, RwLock};
+
+use crate::{Event, Observable};
+
+use super::{Subject, Subscriptions};
+
+pub struct AsyncSubject<T> {
+    inner: Arc<RwLock<Inner<T>>>,
+}
+
+struct Inner<T> {
+    subscriptions: Subscriptions<T>,
+    value: Option<Arc<T>>,
+}
+
+impl<T> Subject for AsyncSubject<T> {
+    type Item = T;
+
+    fn subscribe(&self) -> Observable<Self::Item> {
+        let mut inner = self.inner.write().unwrap();
+        let stream = inner.subscriptions.subscribe();
+
+        if inner.subscriptions.is_closed {
+            if let Some(value) = &inner.value {
+                stream.write().unwrap().push(Event(Arc::clone(value)));
+            }
+        }
+
+        Observable::new(stream)
+    }
+
+    fn close(&self) {
+        self.inner.write().unwrap().close();
+    }
+
+    fn next(&self, value: Self::Item) {
+        let mut inner = self.inner.write().unwrap();
+
+        if !inner.subscriptions.is_closed {
+            inner.value = Some(Arc::new(value));
+        }
+    }
+
+    fn for_each_subscription<F: FnMut(&mut super::Subscription<Self::Item>)>(&self, f: F) {
+        self.inner
+            .write()
+            .unwrap()
+            .subscriptions
+            .for_each_subscription(f);
+    }
+}
+
+#[allow(clippy::new_without_default)]
+impl<T> AsyncSubject<T> {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Inner {
+                subscriptions: Subscriptions::new(),
+                value: None,
+            })),
+        }
+    }
+}
+
+impl<T> Clone for AsyncSubject<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Inner<T> {
+    fn close(&mut self) {
+        if self.subscriptions.is_closed {
+            return;
+        }
+
+        if let Some(value) = self.value.clone() {
+            self.subscriptions.push(&value);
+        }
+
+        self.subscriptions.close();
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::{executor::block_on, StreamExt};
+
+    use crate::{AsyncSubject, Subject};
+
+    #[test]
+    fn emits_last_value_on_close() {
+        block_on(async {
+            let subject = AsyncSubject::new();
+            let stream_a = subject.subscribe();
+
+            subject.next(1);
+            subject.next(2);
+
+            let stream_b = subject.subscribe();
+
+            subject.next(3);
+            subject.close();
+
+            let stream_c = subject.subscribe();
+            let events_a = stream_a.map(|it| *it).collect::<Vec<_>>().await;
+            let events_b = stream_b.map(|it| *it).collect::<Vec<_>>().await;
+            let events_c = stream_c.map(|it| *it).collect::<Vec<_>>().await;
+
+            assert_eq!(events_a, [3]);
+            assert_eq!(events_b, [3]);
+            assert_eq!(events_c, [3]);
+        });
+    }
+
+    #[test]
+    fn emits_nothing_without_value() {
+        block_on(async {
+            let subject = AsyncSubject::<i32>::new();
+            let stream = subject.subscribe();
+
+            subject.close();
+
+            let events = stream.map(|it| *it).collect::<Vec<_>>().await;
+
+            assert_eq!(events, []);
+        });
+    }
+
+    #[test]
+    fn emits_error_on_close() {
+        block_on(async {
+            let subject = AsyncSubject::<Result<i32, &str>>::new();
+            let stream = subject.subscribe();
+
+            subject.next(Ok(1));
+            subject.error("failed");
+
+            let events = stream.map(|it| *it).collect::<Vec<_>>().await;
+
+            assert_eq!(events, [Err("failed")]);
+        });
+    }
+}
@@ -1,60 +1,55 @@
-use std::sync::{Arc, RwLock, Weak};
+use std::sync::{Arc, RwLock};
 
-use crate::{Controller, Event, Observable};
+use crate::{Event, Observable};
 
-use super::Subject;
-
-type Subscription<T> = Weak<RwLock<Controller<Event<T>>>>;
+use super::{bounded_subject, Subject, Subscriptions};
 
 pub struct BehaviorSubject<T> {
-    subscriptions: Vec<Subscription<T>>,
-    is_closed: bool,
+    inner: Arc<RwLock<Inner<T>>>,
+}
+
+struct Inner<T> {
+    subscriptions: Subscriptions<T>,
     value: Arc<T>,
 }
 
 impl<T> Subject for BehaviorSubject<T> {
     type Item = T;
 
-    fn subscribe(&mut self) -> Observable<Self::Item> {
-        let mut stream = Controller::new();
-
-        stream.is_done = self.is_closed;
-
-        let stream = Arc::new(RwLock::new(stream));
+    fn subscribe(&self) -> Observable<Self::Item> {
+        let mut inner = self.inner.write().unwrap();
+        let stream = inner.subscriptions.subscribe();
 
-        self.subscriptions.push(Arc::downgrade(&stream));
-
-        stream.write().unwrap().push(Event(Arc::clone(&self.value)));
+        stream
+            .write()
+            .unwrap()
+            .push(Event(Arc::clone(&inner.value)));
 
         Observable::new(stream)
     }
 
-    fn close(&mut self) {
-        self.is_closed = true;
-
-        self.for_each_subscription(|it| {
-            it.write().unwrap().is_done = true;
-        });
+    fn close(&self) {
+        self.inner.write().unwrap().subscriptions.close();
     }
 
-    fn next(&mut self, value: Self::Item) {
+    fn next(&self, value: Self::Item) {
         let rc = Arc::new(value);
+        let mut inner = self.inner.write().unwrap();
 
-        self.value = Arc::clone(&rc);
+        if inner.subscriptions.is_closed {
+            return;
+        }
 
-        self.for_each_subscription(|it| {
-            it.write().unwrap().push(Event(Arc::clone(&rc)));
-        });
+        inner.value = Arc::clone(&rc);
+        inner.subscriptions.push(&rc);
     }
 
-    fn for_each_subscription<F: FnMut(&mut super::Subscription<Self::Item>)>(&mut self, mut f: F) {
-        self.subscriptions.retain(|sub| {
-            sub.upgrade().is_some_and(|mut it| {
-                f(&mut it);
-
-                true
-            })
-        });
+    fn for_each_subscription<F: FnMut(&mut super::Subscription<Self::Item>)>(&self, f: F) {
+        self.inner
+            .write()
+            .unwrap()
+            .subscriptions
+            .for_each_subscription(f);
     }
 }
 
@@ -62,22 +57,19 @@ impl<T> Subject for BehaviorSubject<T> {
 impl<T> BehaviorSubject<T> {
     pub fn new(value: T) -> Self {
         Self {
-            subscriptions: Vec::new(),
-            is_closed: false,
-            value: value.into(),
+            inner: Arc::new(RwLock::new(Inner {
+                subscriptions: Subscriptions::new(),
+                value: value.into(),
+            })),
         }
     }
 
-    pub fn get_value(&self) -> &T {
-        &self.value
+    pub fn get_value(&self) -> Event<T> {
+        Event(Arc::clone(&self.inner.read().unwrap().value))
     }
 }
 
-impl<T> Drop for BehaviorSubject<T> {
-    fn drop(&mut self) {
-        self.close();
-    }
-}
+bounded_subject!(BehaviorSubject<T>);
 
 #[cfg(test)]
 mod test {
@@ -88,9 +80,9 @@ mod test {
     #[test]
     fn can_subscribe_multiple_times() {
         block_on(async {
-            let mut subject = BehaviorSubject::new(0);
+            let subject = BehaviorSubject::new(0);
 
-            assert_eq!(subject.get_value(), &0);
+            assert_eq!(*subject.get_value(), 0);
 
             let (stream_a, stream_b) = (subject.subscribe(), subject.subscribe());
 
@@ -108,8 +100,8 @@ mod test {
     #[test]
     fn replays_latest_event() {
         block_on(async {
-            let mut subject_a = PublishSubject::new();
-            let mut subject_b = BehaviorSubject::new(0);
+            let subject_a = PublishSubject::new();
+            let subject_b = BehaviorSubject::new(0);
 
             subject_a.next(1);
             subject_b.next(1);
@@ -127,12 +119,22 @@ mod test {
 
     #[test]
     fn can_get_value() {
-        let mut subject = BehaviorSubject::new(0);
+        let subject = BehaviorSubject::new(0);
 
-        assert_eq!(subject.get_value(), &0);
+        assert_eq!(*subject.get_value(), 0);
 
         subject.next(1);
 
-        assert_eq!(subject.get_value(), &1);
+        assert_eq!(*subject.get_value(), 1);
+    }
+
+    #[test]
+    fn ignores_events_after_close() {
+        let subject = BehaviorSubject::new(1);
+
+        subject.close();
+        subject.next(2);
+
+        assert_eq!(*subject.get_value(), 1);
     }
 }
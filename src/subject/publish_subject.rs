@@ -1,55 +1,45 @@
-use std::sync::{Arc, RwLock, Weak};
+use std::sync::{Arc, RwLock};
 
-use crate::{Controller, Event, Observable};
+use crate::Observable;
 
-use super::Subject;
-
-type Subscription<T> = Weak<RwLock<Controller<Event<T>>>>;
+use super::{bounded_subject, Subject, Subscriptions};
 
 pub struct PublishSubject<T> {
-    subscriptions: Vec<Subscription<T>>,
-    is_closed: bool,
+    inner: Arc<RwLock<Inner<T>>>,
+}
+
+struct Inner<T> {
+    subscriptions: Subscriptions<T>,
 }
 
 impl<T> Subject for PublishSubject<T> {
     type Item = T;
 
-    fn subscribe(&mut self) -> Observable<Self::Item> {
-        let mut stream = Controller::new();
-
-        stream.is_done = self.is_closed;
-
-        let stream = Arc::new(RwLock::new(stream));
-
-        self.subscriptions.push(Arc::downgrade(&stream));
-
-        Observable::new(stream)
+    fn subscribe(&self) -> Observable<Self::Item> {
+        Observable::new(self.inner.write().unwrap().subscriptions.subscribe())
     }
 
-    fn close(&mut self) {
-        self.is_closed = true;
-
-        self.for_each_subscription(|it| {
-            it.write().unwrap().is_done = true;
-        });
+    fn close(&self) {
+        self.inner.write().unwrap().subscriptions.close();
     }
 
-    fn next(&mut self, value: Self::Item) {
+    fn next(&self, value: Self::Item) {
         let rc = Arc::new(value);
+        let mut inner = self.inner.write().unwrap();
 
-        self.for_each_subscription(|it| {
-            it.write().unwrap().push(Event(Arc::clone(&rc)));
-        });
-    }
+        if inner.subscriptions.is_closed {
+            return;
+        }
 
-    fn for_each_subscription<F: FnMut(&mut super::Subscription<Self::Item>)>(&mut self, mut f: F) {
-        self.subscriptions.retain(|sub| {
-            sub.upgrade().is_some_and(|mut it| {
-                f(&mut it);
+        inner.subscriptions.push(&rc);
+    }
 
-                true
-            })
-        });
+    fn for_each_subscription<F: FnMut(&mut super::Subscription<Self::Item>)>(&self, f: F) {
+        self.inner
+            .write()
+            .unwrap()
+            .subscriptions
+            .for_each_subscription(f);
     }
 }
 
@@ -57,29 +47,45 @@ impl<T> Subject for PublishSubject<T> {
 impl<T> PublishSubject<T> {
     pub fn new() -> Self {
         Self {
-            subscriptions: Vec::new(),
-            is_closed: false,
+            inner: Arc::new(RwLock::new(Inner {
+                subscriptions: Subscriptions::new(),
+            })),
         }
     }
 }
 
-impl<T> Drop for PublishSubject<T> {
-    fn drop(&mut self) {
-        self.close();
-    }
-}
+bounded_subject!(PublishSubject<T>);
 
 #[cfg(test)]
 mod test {
-    use futures::{executor::block_on, StreamExt};
+    use std::{
+        pin::pin,
+        sync::atomic::{AtomicUsize, Ordering},
+        task::{Context, Poll},
+    };
+
+    use futures::{
+        executor::block_on,
+        future::join,
+        task::{waker, ArcWake},
+        Future, FutureExt, Stream, StreamExt,
+    };
 
-    use crate::RxExt;
+    use crate::{Event, Notification, Overflow, OverflowStrategy, RxExt};
 
     use super::*;
 
+    struct CountingWaker(AtomicUsize);
+
+    impl ArcWake for CountingWaker {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
     #[test]
     fn subscribe_before_events() {
-        let mut subject = PublishSubject::new();
+        let subject = PublishSubject::new();
         let obs = subject.subscribe();
 
         subject.next(1);
@@ -96,7 +102,7 @@ mod test {
 
     #[test]
     fn subscribe_after_events() {
-        let mut subject = PublishSubject::new();
+        let subject = PublishSubject::new();
 
         subject.next(1);
         subject.next(2);
@@ -114,7 +120,7 @@ mod test {
 
     #[test]
     fn ok_event_ownership() {
-        let mut subject = PublishSubject::new();
+        let subject = PublishSubject::new();
         let obs = subject.subscribe();
 
         subject.next(1);
@@ -131,7 +137,7 @@ mod test {
 
     #[test]
     fn err_event_ownership() {
-        let mut subject = PublishSubject::new();
+        let subject = PublishSubject::new();
         let obs = subject.subscribe();
         let some_other_obs = subject.subscribe();
 
@@ -153,7 +159,7 @@ mod test {
 
     #[test]
     fn can_create_events() {
-        let mut subject = PublishSubject::new();
+        let subject = PublishSubject::new();
         let obs = subject
             .subscribe()
             .start_with([Event::from(0), Event::from(1), Event::from(2)]);
@@ -166,4 +172,267 @@ mod test {
             assert_eq!(res, [0, 1, 2]);
         });
     }
+
+    #[test]
+    fn error_terminates_subscriptions() {
+        let subject = PublishSubject::new();
+        let obs = subject.subscribe();
+
+        subject.next(Ok(1));
+        subject.error("failed");
+
+        block_on(async {
+            let res = obs
+                .map(|it| it.try_unwrap().unwrap())
+                .try_materialize()
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(res, [Notification::Next(1), Notification::Error("failed")]);
+        });
+    }
+
+    #[test]
+    fn ignores_events_after_error() {
+        let subject = PublishSubject::new();
+        let obs = subject.subscribe();
+
+        subject.next(Ok(1));
+        subject.error("failed");
+        subject.next(Ok(2));
+
+        block_on(async {
+            let res = obs
+                .map(|it| it.try_unwrap().unwrap())
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(res, [Ok(1), Err("failed")]);
+        });
+    }
+
+    #[test]
+    fn wakes_only_on_push_or_close() {
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = waker(Arc::clone(&counter));
+        let mut cx = Context::from_waker(&waker);
+        let subject = PublishSubject::new();
+        let mut obs = pin!(subject.subscribe());
+
+        assert!(obs.as_mut().poll_next(&mut cx).is_pending());
+        assert!(obs.as_mut().poll_next(&mut cx).is_pending());
+        assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+
+        subject.next(1);
+
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            obs.as_mut().poll_next(&mut cx).map(|it| it.map(|it| *it)),
+            Poll::Ready(Some(1))
+        );
+        assert!(obs.as_mut().poll_next(&mut cx).is_pending());
+
+        subject.close();
+
+        assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+        assert!(obs.as_mut().poll_next(&mut cx).is_ready());
+    }
+
+    #[test]
+    fn bounded_drop_oldest() {
+        let subject = PublishSubject::new().bounded(2, OverflowStrategy::DropOldest);
+        let obs = subject.subscribe();
+
+        subject.next(1);
+        subject.next(2);
+        subject.next(3);
+        subject.close();
+
+        block_on(async {
+            let res = obs.map(|it| *it).collect::<Vec<i32>>().await;
+
+            assert_eq!(res, [2, 3]);
+        });
+    }
+
+    #[test]
+    fn bounded_drop_newest() {
+        let subject = PublishSubject::new().bounded(2, OverflowStrategy::DropNewest);
+        let obs = subject.subscribe();
+
+        subject.next(1);
+        subject.next(2);
+        subject.next(3);
+        subject.close();
+
+        block_on(async {
+            let res = obs.map(|it| *it).collect::<Vec<i32>>().await;
+
+            assert_eq!(res, [1, 2]);
+        });
+    }
+
+    #[test]
+    fn bounded_terminate() {
+        let subject = PublishSubject::new().bounded(2, OverflowStrategy::Terminate);
+        let mut obs = subject.subscribe();
+
+        subject.next(1);
+        subject.next(2);
+
+        assert!(!obs.has_overflowed());
+
+        subject.next(3);
+        subject.next(4);
+
+        assert!(obs.has_overflowed());
+
+        block_on(async {
+            let res = (&mut obs).map(|it| *it).collect::<Vec<i32>>().await;
+
+            assert_eq!(res, [1, 2]);
+        });
+    }
+
+    #[test]
+    fn bounded_with_error() {
+        let subject = PublishSubject::<Result<i32, Overflow>>::new().bounded_with_error(2);
+        let obs = subject.subscribe();
+
+        subject.next(Ok(1));
+        subject.next(Ok(2));
+        subject.next(Ok(3));
+        subject.next(Ok(4));
+
+        block_on(async {
+            let res = obs
+                .map(|it| it.try_unwrap().unwrap())
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(res, [Ok(1), Ok(2), Err(Overflow)]);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be greater than 0")]
+    fn bounded_zero_capacity() {
+        let _ = PublishSubject::<i32>::new().bounded(0, OverflowStrategy::Backpressure);
+    }
+
+    #[test]
+    fn bounded_backpressure() {
+        let subject = PublishSubject::new().bounded(1, OverflowStrategy::Backpressure);
+        let obs = subject.subscribe();
+
+        block_on(async {
+            let producer = async {
+                for i in 0..5 {
+                    subject.next_async(i).await;
+                }
+
+                subject.close();
+            };
+            let consumer = obs.map(|it| *it).collect::<Vec<i32>>();
+            let (_, res) = join(producer, consumer).await;
+
+            assert_eq!(res, [0, 1, 2, 3, 4]);
+        });
+    }
+
+    #[test]
+    fn bounded_backpressure_waits_for_room() {
+        let subject = PublishSubject::new().bounded(1, OverflowStrategy::Backpressure);
+        let mut obs = subject.subscribe();
+
+        subject.next(0);
+
+        assert!(subject.next_async(1).now_or_never().is_none());
+        assert_eq!(block_on(obs.next()).map(|it| *it), Some(0));
+        assert!(subject.next_async(1).now_or_never().is_some());
+
+        subject.close();
+
+        block_on(async {
+            let res = obs.map(|it| *it).collect::<Vec<i32>>().await;
+
+            assert_eq!(res, [1]);
+        });
+    }
+
+    #[test]
+    fn bounded_backpressure_consumer_dropped() {
+        let subject = PublishSubject::new().bounded(1, OverflowStrategy::Backpressure);
+        let obs = subject.subscribe();
+
+        subject.next(0);
+
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = waker(Arc::clone(&counter));
+        let mut cx = Context::from_waker(&waker);
+        let mut producer = pin!(subject.next_async(1));
+
+        assert!(producer.as_mut().poll(&mut cx).is_pending());
+
+        drop(obs);
+
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        assert!(producer.as_mut().poll(&mut cx).is_ready());
+    }
+
+    #[test]
+    fn bounded_backpressure_subject_closed() {
+        let subject = PublishSubject::new().bounded(1, OverflowStrategy::Backpressure);
+        let _obs = subject.subscribe();
+
+        subject.next(0);
+
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = waker(Arc::clone(&counter));
+        let mut cx = Context::from_waker(&waker);
+        let mut producer = pin!(subject.next_async(1));
+
+        assert!(producer.as_mut().poll(&mut cx).is_pending());
+
+        subject.close();
+
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        assert!(producer.as_mut().poll(&mut cx).is_ready());
+    }
+
+    #[test]
+    fn multiple_producers() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let subject = PublishSubject::new();
+        let obs = subject.subscribe();
+
+        assert_send_sync(&subject);
+
+        let handles = (0..4)
+            .map(|i| {
+                let subject = subject.clone();
+
+                std::thread::spawn(move || {
+                    for j in 0..10 {
+                        subject.next(i * 10 + j);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        drop(subject);
+
+        block_on(async {
+            let mut res = obs.map(|it| *it).collect::<Vec<i32>>().await;
+
+            res.sort();
+
+            assert_eq!(res, (0..40).collect::<Vec<_>>());
+        });
+    }
 }
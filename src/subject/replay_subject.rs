@@ -1,121 +1,193 @@
 use std::{
     collections::VecDeque,
-    sync::{Arc, RwLock, Weak},
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
 };
 
-use crate::{Controller, Event, Observable};
-
-use super::Subject;
+use crate::{
+    scheduler::{Clock, SystemClock},
+    Event, Observable, Scheduler,
+};
 
-type Subscription<T> = Weak<RwLock<Controller<Event<T>>>>;
+use super::{bounded_subject, Subject, Subscriptions};
 
 pub(crate) enum ReplayStrategy {
     BufferSize(usize),
+    Window(Duration, Option<usize>),
     Unbounded,
 }
 
-pub struct ReplaySubject<T> {
+pub struct ReplaySubject<T, C = SystemClock> {
+    inner: Arc<RwLock<Inner<T, C>>>,
+}
+
+struct Inner<T, C> {
+    clock: C,
     replay_strategy: ReplayStrategy,
-    subscriptions: Vec<Subscription<T>>,
-    is_closed: bool,
-    buffer: VecDeque<Arc<T>>,
+    subscriptions: Subscriptions<T>,
+    /// Events are only timestamped when replaying a `Window`.
+    buffer: VecDeque<(Option<Instant>, Arc<T>)>,
 }
 
-impl<T> Subject for ReplaySubject<T> {
+impl<T, C: Clock> Subject for ReplaySubject<T, C> {
     type Item = T;
 
-    fn subscribe(&mut self) -> Observable<Self::Item> {
-        let mut stream = Controller::new();
-
-        stream.is_done = self.is_closed;
+    fn subscribe(&self) -> Observable<Self::Item> {
+        let mut inner = self.inner.write().unwrap();
+        let stream = inner.subscriptions.subscribe();
 
-        let stream = Arc::new(RwLock::new(stream));
+        inner.evict_expired();
 
-        self.subscriptions.push(Arc::downgrade(&stream));
+        // a bounded subscription only replays its newest events, so that replaying cannot overflow it
+        let skip = match inner.subscriptions.bounds {
+            Some((capacity, _)) => inner.buffer.len().saturating_sub(capacity),
+            None => 0,
+        };
 
-        for event in &self.buffer {
+        for (_, event) in inner.buffer.iter().skip(skip) {
             stream.write().unwrap().push(Event(Arc::clone(event)));
         }
 
         Observable::new(stream)
     }
 
-    fn close(&mut self) {
-        self.is_closed = true;
-
-        self.for_each_subscription(|it| {
-            it.write().unwrap().is_done = true;
-        });
+    fn close(&self) {
+        self.inner.write().unwrap().subscriptions.close();
     }
 
-    fn next(&mut self, value: Self::Item) {
+    fn next(&self, value: Self::Item) {
         let rc = Arc::new(value);
+        let mut inner = self.inner.write().unwrap();
 
-        if let ReplayStrategy::BufferSize(size) = &self.replay_strategy {
-            if self.buffer.len() == *size {
-                self.buffer.pop_front();
+        if inner.subscriptions.is_closed {
+            return;
+        }
+
+        inner.evict_expired();
+
+        let (timestamp, size) = match inner.replay_strategy {
+            ReplayStrategy::BufferSize(size) => (None, Some(size)),
+            ReplayStrategy::Window(_, size) => (Some(inner.clock.now()), size),
+            ReplayStrategy::Unbounded => (None, None),
+        };
+
+        if let Some(size) = size {
+            while !inner.buffer.is_empty() && inner.buffer.len() >= size {
+                inner.buffer.pop_front();
             }
         }
 
-        self.buffer.push_back(Arc::clone(&rc));
+        if size != Some(0) {
+            inner.buffer.push_back((timestamp, Arc::clone(&rc)));
+        }
 
-        self.for_each_subscription(|it| {
-            it.write().unwrap().push(Event(Arc::clone(&rc)));
-        });
+        inner.subscriptions.push(&rc);
     }
 
-    fn for_each_subscription<F: FnMut(&mut super::Subscription<Self::Item>)>(&mut self, mut f: F) {
-        self.subscriptions.retain(|sub| {
-            sub.upgrade().is_some_and(|mut it| {
-                f(&mut it);
-
-                true
-            })
-        });
+    fn for_each_subscription<F: FnMut(&mut super::Subscription<Self::Item>)>(&self, f: F) {
+        self.inner
+            .write()
+            .unwrap()
+            .subscriptions
+            .for_each_subscription(f);
     }
 }
 
 #[allow(clippy::new_without_default)]
 impl<T> ReplaySubject<T> {
     pub fn new() -> Self {
-        Self {
-            replay_strategy: ReplayStrategy::Unbounded,
-            subscriptions: Vec::new(),
-            is_closed: false,
-            buffer: VecDeque::new(),
-        }
+        Self::with_strategy(ReplayStrategy::Unbounded, VecDeque::new(), SystemClock)
     }
 
     pub fn buffer_size(size: usize) -> Self {
-        Self {
-            replay_strategy: ReplayStrategy::BufferSize(size),
-            subscriptions: Vec::new(),
-            is_closed: false,
-            buffer: VecDeque::with_capacity(size),
-        }
+        Self::with_strategy(
+            ReplayStrategy::BufferSize(size),
+            VecDeque::with_capacity(size),
+            SystemClock,
+        )
+    }
+
+    /// Only replays events which were emitted within the last `duration`,
+    /// optionally also limited to the last `buffer_size` events.
+    pub fn window(duration: Duration, buffer_size: Option<usize>) -> Self {
+        Self::with_strategy(
+            ReplayStrategy::Window(duration, buffer_size),
+            VecDeque::new(),
+            SystemClock,
+        )
+    }
+}
+
+impl<T, Sch: Scheduler> ReplaySubject<T, Sch> {
+    /// Like `window`, but measures time using `scheduler` instead of the wall clock.
+    pub fn window_with_scheduler(
+        duration: Duration,
+        buffer_size: Option<usize>,
+        scheduler: &Sch,
+    ) -> Self {
+        Self::with_strategy(
+            ReplayStrategy::Window(duration, buffer_size),
+            VecDeque::new(),
+            scheduler.clone(),
+        )
     }
+}
 
+impl<T, C: Clock> ReplaySubject<T, C> {
     pub fn buffer_len(&self) -> usize {
-        self.buffer.len()
+        let mut inner = self.inner.write().unwrap();
+
+        inner.evict_expired();
+        inner.buffer.len()
+    }
+
+    fn with_strategy(
+        replay_strategy: ReplayStrategy,
+        buffer: VecDeque<(Option<Instant>, Arc<T>)>,
+        clock: C,
+    ) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Inner {
+                clock,
+                replay_strategy,
+                subscriptions: Subscriptions::new(),
+                buffer,
+            })),
+        }
     }
 }
 
-impl<T> Drop for ReplaySubject<T> {
-    fn drop(&mut self) {
-        self.close();
+bounded_subject!(
+    /// A new subscription only replays the newest `capacity` buffered events.
+    ReplaySubject<T, C: Clock>
+);
+
+impl<T, C: Clock> Inner<T, C> {
+    fn evict_expired(&mut self) {
+        if let ReplayStrategy::Window(duration, _) = self.replay_strategy {
+            let now = self.clock.now();
+
+            while self.buffer.front().is_some_and(|(timestamp, _)| {
+                timestamp.is_some_and(|it| now.duration_since(it) > duration)
+            }) {
+                self.buffer.pop_front();
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use futures::{executor::block_on, StreamExt};
 
-    use crate::{PublishSubject, ReplaySubject, Subject};
+    use crate::{OverflowStrategy, PublishSubject, ReplaySubject, Subject, TestScheduler};
 
     #[test]
     fn can_subscribe_multiple_times() {
         block_on(async {
-            let mut subject = ReplaySubject::new();
+            let subject = ReplaySubject::new();
 
             let (stream_a, stream_b) = (subject.subscribe(), subject.subscribe());
 
@@ -133,8 +205,8 @@ mod test {
     #[test]
     fn replays_latest_events() {
         block_on(async {
-            let mut subject_a = PublishSubject::new();
-            let mut subject_b = ReplaySubject::new();
+            let subject_a = PublishSubject::new();
+            let subject_b = ReplaySubject::new();
 
             subject_a.next(1);
             subject_a.next(2);
@@ -153,4 +225,107 @@ mod test {
             assert_eq!(events_b, [1, 2, 3]);
         });
     }
+
+    #[test]
+    fn replays_events_within_window() {
+        let scheduler = TestScheduler::new();
+        let subject =
+            ReplaySubject::window_with_scheduler(Duration::from_millis(50), None, &scheduler);
+
+        subject.next(1);
+        subject.next(2);
+
+        scheduler.advance_by(Duration::from_millis(50));
+
+        subject.next(3);
+
+        scheduler.advance_by(Duration::from_millis(1));
+
+        subject.next(4);
+        subject.close();
+
+        block_on(async {
+            let events = subject.subscribe().map(|it| *it).collect::<Vec<_>>().await;
+
+            assert_eq!(events, [3, 4]);
+        });
+    }
+
+    #[test]
+    fn replays_events_within_window_and_buffer_size() {
+        block_on(async {
+            let subject = ReplaySubject::window(Duration::from_secs(60), Some(2));
+
+            subject.next(1);
+            subject.next(2);
+            subject.next(3);
+            subject.close();
+
+            let events = subject.subscribe().map(|it| *it).collect::<Vec<_>>().await;
+
+            assert_eq!(events, [2, 3]);
+            assert_eq!(subject.buffer_len(), 2);
+        });
+    }
+
+    #[test]
+    fn ignores_events_after_close() {
+        block_on(async {
+            let subject = ReplaySubject::new();
+
+            subject.next(1);
+            subject.close();
+            subject.next(2);
+
+            let events = subject.subscribe().map(|it| *it).collect::<Vec<_>>().await;
+
+            assert_eq!(events, [1]);
+        });
+    }
+
+    #[test]
+    fn bounded_replays_newest_events() {
+        let subject = ReplaySubject::buffer_size(5).bounded(2, OverflowStrategy::Terminate);
+
+        for i in 0..5 {
+            subject.next(i);
+        }
+
+        let mut obs = subject.subscribe();
+
+        assert!(!obs.has_overflowed());
+
+        subject.next(5);
+
+        assert!(obs.has_overflowed());
+
+        block_on(async {
+            let events = (&mut obs).map(|it| *it).collect::<Vec<_>>().await;
+
+            assert_eq!(events, [3, 4]);
+        });
+    }
+
+    #[test]
+    fn replays_nothing_with_zero_buffer_size() {
+        block_on(async {
+            let subject = ReplaySubject::buffer_size(0);
+            let early = subject.subscribe();
+
+            subject.next(1);
+            subject.close();
+
+            let events = subject.subscribe().map(|it| *it).collect::<Vec<_>>().await;
+
+            assert_eq!(events, []);
+            assert_eq!(early.map(|it| *it).collect::<Vec<_>>().await, [1]);
+            assert_eq!(subject.buffer_len(), 0);
+
+            let subject = ReplaySubject::window(Duration::from_secs(60), Some(0));
+
+            subject.next(1);
+
+            assert_eq!(subject.buffer_len(), 0);
+        });
+    }
 }
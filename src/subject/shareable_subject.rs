@@ -1,17 +1,19 @@
 use std::{
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
 use futures::{stream::Fuse, Stream, StreamExt};
 
-use crate::Observable;
+use crate::{stream::waker_set::WakerSet, Observable};
 
 use super::Subject;
 
 pub(crate) struct ShareableSubject<S: Stream, Sub: Subject<Item = S::Item>> {
     stream: Pin<Box<Fuse<S>>>,
     subject: Sub,
+    wakers: Arc<WakerSet>,
 }
 
 impl<S: Stream, Sub: Subject<Item = S::Item>> ShareableSubject<S, Sub> {
@@ -19,18 +21,27 @@ impl<S: Stream, Sub: Subject<Item = S::Item>> ShareableSubject<S, Sub> {
         Self {
             stream: Box::pin(stream.fuse()),
             subject,
+            wakers: WakerSet::new(),
         }
     }
 
-    pub(crate) fn subscribe(&mut self) -> Observable<S::Item> {
+    pub(crate) fn subscribe(&self) -> Observable<S::Item> {
         self.subject.subscribe()
     }
 
+    /// Polls the source `Stream` once, on behalf of all subscriptions,
+    /// which are all woken when the source is ready again.
     pub(crate) fn poll_next(&mut self, cx: &mut Context<'_>) {
-        match self.stream.poll_next_unpin(cx) {
-            Poll::Ready(Some(value)) => self.subject.next(value),
-            Poll::Ready(None) => self.subject.close(),
+        let Self {
+            stream,
+            subject,
+            wakers,
+        } = self;
+
+        wakers.poll_with(cx, |cx| match stream.poll_next_unpin(cx) {
+            Poll::Ready(Some(value)) => subject.next(value),
+            Poll::Ready(None) => subject.close(),
             Poll::Pending => {}
-        }
+        });
     }
 }